@@ -0,0 +1,29 @@
+fn main() {
+    #[cfg(feature = "cffi")]
+    generate_c_header();
+}
+
+/// Generates the C header of the functions and types in `src/cffi.rs` into
+/// `OUT_DIR`. The header checked in at `include/wallet_compatible_derivation.h`
+/// is only overwritten if the `WCD_UPDATE_C_HEADER` environment variable is
+/// set, so that building never modifies the source tree.
+#[cfg(feature = "cffi")]
+fn generate_c_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src/cffi.rs");
+    println!("cargo:rerun-if-env-changed=WCD_UPDATE_C_HEADER");
+    let header = cbindgen::Builder::new()
+        .with_src(format!("{crate_dir}/src/cffi.rs"))
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("WALLET_COMPATIBLE_DERIVATION_H")
+        .with_documentation(true)
+        .generate()
+        .expect("Should be able to generate C header.");
+    header.write_to_file(format!("{out_dir}/wallet_compatible_derivation.h"));
+    if std::env::var_os("WCD_UPDATE_C_HEADER").is_some() {
+        header.write_to_file(format!(
+            "{crate_dir}/include/wallet_compatible_derivation.h"
+        ));
+    }
+}
@@ -0,0 +1,91 @@
+#ifndef WALLET_COMPATIBLE_DERIVATION_H
+#define WALLET_COMPATIBLE_DERIVATION_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The capacity of `WcdAccount::address`, enough for the longest account address
+ * of any supported network, including the terminating NUL.
+ */
+#define WCD_ADDRESS_CAPACITY 128
+
+/**
+ * The result of calling a `wcd_` function, `WcdOk` on success.
+ */
+typedef enum WcdResult {
+  WcdOk = 0,
+  WcdNullPointer = 1,
+  WcdInvalidUtf8 = 2,
+  WcdInvalidMnemonic = 3,
+  WcdUnknownNetworkID = 4,
+  WcdInvalidIndex = 5,
+  WcdDerivationFailed = 6,
+} WcdResult;
+
+/**
+ * A derived account, with fixed size buffers so that it can be allocated by
+ * the caller, e.g. on the stack.
+ */
+typedef struct WcdAccount {
+  /**
+   * The NUL terminated bech32m encoded address of the account.
+   */
+  char address[WCD_ADDRESS_CAPACITY];
+  /**
+   * The Ed25519 public key of the account.
+   */
+  uint8_t public_key[32];
+  /**
+   * The Ed25519 private key of the account, all zeroes unless
+   * `has_private_key` is `true`.
+   */
+  uint8_t private_key[32];
+  /**
+   * If `private_key` was filled in.
+   */
+  bool has_private_key;
+  /**
+   * The index of the account.
+   */
+  uint32_t index;
+  /**
+   * The discriminant of the network of the account, e.g. `1` for Mainnet.
+   */
+  uint8_t network_id;
+} WcdAccount;
+
+/**
+ * Derives the account at `index` on the network with discriminant `network_id`
+ * using the NUL terminated UTF-8 `mnemonic` and BIP-39 `passphrase` (can be the
+ * empty string), writing it to `out`. The shorthands of the test mnemonics,
+ * e.g. `"__test_0"`, are not accepted. The private key is only written if
+ * `include_private_key` is `true`.
+ *
+ * `out` is only written to if `WcdOk` is returned.
+ *
+ * # Safety
+ *
+ * `mnemonic` and `passphrase` MUST be NUL terminated strings, and `out` MUST
+ * point to memory valid for writing a `WcdAccount`, or be null.
+ */
+enum WcdResult wcd_derive_account(const char *mnemonic,
+                                  const char *passphrase,
+                                  uint8_t network_id,
+                                  uint32_t index,
+                                  bool include_private_key,
+                                  struct WcdAccount *out);
+
+/**
+ * Zeroizes all fields of `account`, including the private key, does nothing
+ * if `account` is null.
+ *
+ * # Safety
+ *
+ * `account` MUST point to a valid `WcdAccount`, or be null.
+ */
+void wcd_zeroize_account(struct WcdAccount *account);
+
+#endif /* WALLET_COMPATIBLE_DERIVATION_H */
@@ -1,6 +1,9 @@
 use crate::prelude::*;
 
-use ed25519_dalek::{PublicKey, SecretKey};
+use ed25519_dalek::{ExpandedSecretKey, PublicKey, SecretKey};
+use radix_common::prelude::{blake2b_256_hash, AddressBech32Decoder, IsHash};
+use std::ops::Range;
+use subtle::ConstantTimeEq;
 
 /// A tuple of keys and Radix Babylon Account address, for a
 /// virtual account - an account that the Radix Public Ledger
@@ -35,6 +38,66 @@ pub struct Account {
     pub factor_source_id: FactorSourceID,
 }
 
+impl std::fmt::Debug for Account {
+    /// Redacts the private key, all other fields are public information.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Account")
+            .field("network_id", &self.network_id)
+            .field("private_key", &format_args!("***redacted***"))
+            .field("public_key", &format_args!("{}", self.public_key.to_hex()))
+            .field("address", &self.address)
+            .field("index", &self.index)
+            .field("path", &self.path)
+            .field("factor_source_id", &self.factor_source_id)
+            .finish()
+    }
+}
+
+impl Account {
+    /// The public fields accounts are compared by, in order, the private key is
+    /// never compared. Ties of `(network_id, path, index)` - accounts derived from
+    /// different mnemonics - are broken by `factor_source_id` and `address`.
+    fn ordering_key(
+        &self,
+    ) -> (
+        &NetworkID,
+        &AccountPath,
+        HDPathComponentValue,
+        &FactorSourceID,
+        &str,
+    ) {
+        (
+            &self.network_id,
+            &self.path,
+            self.index,
+            &self.factor_source_id,
+            &self.address,
+        )
+    }
+}
+
+impl PartialEq for Account {
+    fn eq(&self, other: &Self) -> bool {
+        self.ordering_key() == other.ordering_key()
+    }
+}
+
+impl Eq for Account {}
+
+impl PartialOrd for Account {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Account {
+    /// Orders accounts in canonical derivation order, by `(network_id, path, index)`,
+    /// so that sorting accounts from an unordered source sorts them by index.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.ordering_key().cmp(&other.ordering_key())
+    }
+}
+
 impl Account {
     pub fn to_string_include_private_key(&self, include_private_key: bool) -> String {
         let private_key_or_empty = if include_private_key {
@@ -64,31 +127,354 @@ PublicKey: {}
     /// Derives a simple [`Account`] using the `mnemonic` and BIP-39 `passphrase` (can be the empty string) using the hierarchical deterministic derivation path `path`.
     ///
     /// See [`Account`] for more details, but in short it is an Address + key pair.
+    ///
+    /// Computes the BIP-39 seed every time, use `derive_from_seed` to derive many
+    /// accounts.
+    ///
+    /// Returns `Err` if key derivation or address encoding fails, which never
+    /// happens for a valid `path`, see `derive_unwrap`.
     pub fn derive(
-        mnemonic: &Mnemonic24Words,
-        passphrase: impl AsRef<str>,
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        path: &AccountPath,
+    ) -> Result<Self> {
+        Self::derive_from_seed(&Seed::from_mnemonic(mnemonic, passphrase), path)
+    }
+
+    /// Like `derive` but panics instead of returning `Err`, convenient in tests
+    /// and examples.
+    pub fn derive_unwrap(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
         path: &AccountPath,
     ) -> Self {
-        let network_id = path.network_id();
-        let seed = mnemonic.to_seed(passphrase.as_ref());
+        Self::derive(mnemonic, passphrase, path).expect("Should be able to derive account.")
+    }
+
+    /// Derives accounts on `network_id` for every index in `range`, computing the
+    /// BIP-39 seed only once, which is much faster than calling `derive` for
+    /// every index.
+    ///
+    /// The accounts are ordered by ascending index, see `derive_range_cancellable`.
+    pub fn derive_range(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+    ) -> Result<Vec<Self>> {
+        Self::derive_range_cancellable(mnemonic, passphrase, network_id, range, || false)
+    }
+
+    /// Like `derive_range` but derives the accounts on multiple threads, all
+    /// deriving from the same read-only BIP-39 seed, which is computed only once.
+    ///
+    /// The accounts are ordered by ascending index, just like `derive_range`.
+    #[cfg(feature = "parallel")]
+    pub fn derive_range_par(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+    ) -> Result<Vec<Self>> {
+        use rayon::prelude::*;
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
         let factor_source_id = FactorSourceID::from_seed(&seed);
-        let (private_key, public_key) = derive_ed25519_key_pair(&seed, &path.0.inner());
-        let address = derive_address(&public_key, &network_id);
+        range
+            .into_par_iter()
+            .map(|index| {
+                Self::derive_from_seed_with_id(
+                    &seed,
+                    &factor_source_id,
+                    &AccountPath::new(network_id, index),
+                )
+            })
+            .collect()
+    }
 
-        Self {
+    /// Derives accounts on `network_id` for every index in `range`, computing the
+    /// BIP-39 seed only once.
+    ///
+    /// The accounts are guaranteed to be ordered by ascending index, i.e. the
+    /// account at position `i` has index `range.start + i`.
+    ///
+    /// Before each account is derived `should_cancel` is called, if it returns `true`
+    /// derivation stops early and the accounts derived so far are returned. This
+    /// allows e.g. a GUI to let the user abort a long running derivation.
+    ///
+    /// Returns `Err` as soon as deriving any account fails.
+    pub fn derive_range_cancellable(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+        should_cancel: impl Fn() -> bool,
+    ) -> Result<Vec<Self>> {
+        Self::derive_range_instrumented(
+            mnemonic,
+            passphrase,
+            network_id,
+            range,
+            should_cancel,
+            #[cfg(feature = "metrics")]
+            None,
+        )
+    }
+
+    /// The derivation loop of `derive_range_cancellable`, which with the
+    /// `metrics` feature also records the time spent into `stats`, if any, see
+    /// `derive_range_with_stats`.
+    pub(crate) fn derive_range_instrumented(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+        should_cancel: impl Fn() -> bool,
+        #[cfg(feature = "metrics")] stats: Option<&mut DerivationStats>,
+    ) -> Result<Vec<Self>> {
+        #[cfg(feature = "metrics")]
+        let started_at = std::time::Instant::now();
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        #[cfg(feature = "metrics")]
+        let seed_derivation = started_at.elapsed();
+
+        #[cfg(feature = "metrics")]
+        let started_at = std::time::Instant::now();
+        let factor_source_id = FactorSourceID::from_seed(&seed);
+        let mut accounts = Vec::<Self>::new();
+        for index in range {
+            if should_cancel() {
+                break;
+            }
+            let path = AccountPath::new(network_id, index);
+            accounts.push(Self::derive_from_seed_with_id(
+                &seed,
+                &factor_source_id,
+                &path,
+            )?);
+        }
+
+        #[cfg(feature = "metrics")]
+        if let Some(stats) = stats {
+            *stats = DerivationStats {
+                count: accounts.len(),
+                seed_derivation,
+                key_derivation: started_at.elapsed(),
+            };
+        }
+        Ok(accounts)
+    }
+
+    /// Derives accounts on `network_id` for every index in `range`, in ascending
+    /// order, computing the BIP-39 seed only once, handing each account to `f`.
+    ///
+    /// Unlike `derive_range` the accounts are not collected, each account is
+    /// dropped, thus zeroized, as soon as `f` returns, before the next account
+    /// is derived, keeping at most one private key in memory at a time (unless
+    /// `f` keeps the accounts). Useful to e.g. write many accounts to disk.
+    ///
+    /// Returns `Err` as soon as deriving any account fails, without calling `f`
+    /// for any further account.
+    pub fn for_each_in_range(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+        f: impl FnMut(Self),
+    ) -> Result<()> {
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        Self::for_each_in_range_from_seed(&seed, network_id, range, f)
+    }
+
+    /// Like `for_each_in_range` but derives from the BIP-39 `seed`, e.g. if only
+    /// the seed is known, not the mnemonic. The `FactorSourceID` of `seed` is
+    /// computed only once.
+    pub fn for_each_in_range_from_seed(
+        seed: &Seed,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+        mut f: impl FnMut(Self),
+    ) -> Result<()> {
+        let factor_source_id = FactorSourceID::from_seed(seed);
+        for index in range {
+            f(Self::derive_from_seed_with_id(
+                seed,
+                &factor_source_id,
+                &AccountPath::new(network_id, index),
+            )?);
+        }
+        Ok(())
+    }
+
+    /// Finds the index of the account with `address` on `network_id`, by deriving
+    /// the accounts at every index in `search_range`, in ascending order, using
+    /// the `mnemonic` and BIP-39 `passphrase` (can be the empty string).
+    ///
+    /// Returns the first matching index, without deriving any further accounts,
+    /// or `None` if no account in `search_range` has `address`. The BIP-39 seed
+    /// is computed only once.
+    ///
+    /// Any index below `2^31` can be searched, including the upper half of the
+    /// keyspace, `2^30..2^31`, which Radix wallets use for securified entities.
+    ///
+    /// Returns `Err(Error::InvalidDerivationRange)` if `search_range` contains an
+    /// index of `2^31` or larger, since those cannot be hardened, before deriving
+    /// any account.
+    pub fn find_index(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        address: impl AsRef<str>,
+        search_range: Range<EntityIndex>,
+    ) -> Result<Option<HDPathComponentValue>> {
+        if !search_range.is_empty() && search_range.end > harden(0) {
+            return Err(Error::InvalidDerivationRange {
+                start: search_range.start,
+                count: search_range.end - search_range.start,
+            });
+        }
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        for index in search_range {
+            let path = AccountPath::new(network_id, index);
+            let (private_key, public_key) = Ed25519Scheme::derive(&seed, &path.0)?;
+            drop(private_key);
+            if Ed25519Scheme::address(&public_key, network_id)? == address.as_ref() {
+                return Ok(Some(index));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Derives an [`Account`] from the BIP-39 `seed` using the hierarchical
+    /// deterministic derivation path `path`, without recomputing the seed.
+    ///
+    /// Accounts are always derived with [`Ed25519Scheme`], the curve used by the
+    /// Radix Wallet.
+    pub fn derive_from_seed(seed: &Seed, path: &AccountPath) -> Result<Self> {
+        Self::derive_from_seed_with_id(seed, &FactorSourceID::from_seed(seed), path)
+    }
+
+    /// Like `derive_from_seed` with the `FactorSourceID` of `seed` computed by the
+    /// caller. Computing it costs as much as deriving an account, so when deriving
+    /// many accounts from the same seed it is computed only once.
+    pub(crate) fn derive_from_seed_with_id(
+        seed: &Seed,
+        factor_source_id: &FactorSourceID,
+        path: &AccountPath,
+    ) -> Result<Self> {
+        let network_id = path.network_id();
+        let (private_key, public_key) = Ed25519Scheme::derive(seed, &path.0)?;
+        let address = Ed25519Scheme::address(&public_key, &network_id)?;
+
+        Ok(Self {
             network_id,
             private_key,
             public_key,
             address,
             index: path.clone().account_index(),
             path: path.clone(),
-            factor_source_id,
-        }
+            factor_source_id: factor_source_id.clone(),
+        })
+    }
+
+    /// Derives an [`Account`] from the hex of a 64 bytes BIP-39 seed, see
+    /// `Seed::from_hex`, e.g. if only the seed is known, not the mnemonic.
+    ///
+    /// Returns `Err` if `seed_hex` is not the hex of exactly 64 bytes.
+    pub fn derive_from_hex_seed(seed_hex: &str, path: &AccountPath) -> Result<Self> {
+        Self::derive_from_seed(&Seed::from_hex(seed_hex)?, path)
+    }
+
+    /// Derives the [ROLA][rola] authentication signing key pair of the account
+    /// at `index` on `network_id`, i.e. the key pair at the path with key kind
+    /// `KeyKind::AuthenticationSigning`, used by dApps to verify login proofs.
+    ///
+    /// [rola]: https://docs.radixdlt.com/docs/rola-radix-off-ledger-auth
+    pub fn derive_rola_key(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        index: EntityIndex,
+    ) -> Result<Keypair> {
+        let path =
+            AccountPath::new_with_key_kind(network_id, KeyKind::AuthenticationSigning, index);
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        let (secret, public) = derive_ed25519_key_pair(seed.as_bytes(), &path.0.inner())?;
+        Ok(Keypair { secret, public })
     }
 
     pub fn is_zeroized(&self) -> bool {
         self.private_key.to_bytes() == [0; 32]
     }
+
+    /// The 32 bytes of the Ed25519 public key of this account.
+    pub fn public_key_bytes(&self) -> [u8; 32] {
+        self.public_key.to_bytes()
+    }
+
+    /// The public key of this account in the Radix Engine representation, as
+    /// used when deriving the `address`, and in Scrypto and transaction contexts.
+    pub fn public_key_radix(&self) -> Ed25519PublicKey {
+        radix_engine_public_key(&self.public_key)
+    }
+
+    /// The raw 30 bytes `NodeId` of this account, i.e. the `address` before it
+    /// was bech32m encoded, as used in Scrypto and transaction contexts.
+    pub fn node_id(&self) -> [u8; 30] {
+        derive_component_address(&self.public_key).as_node_id().0
+    }
+
+    /// Signs `message` with the private key of this account, returning the 64
+    /// bytes Ed25519 signature.
+    ///
+    /// The expanded secret key, from which the nonce is derived, is zeroized
+    /// when dropped.
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        ExpandedSecretKey::from(&self.private_key)
+            .sign(message, &self.public_key)
+            .to_bytes()
+    }
+
+    /// Signs the `blake2b_256_hash` of `message`, which is how Radix signs e.g.
+    /// transactions and [ROLA][rola] challenges.
+    ///
+    /// [rola]: https://docs.radixdlt.com/docs/rola-radix-off-ledger-auth
+    pub fn sign_prehashed_blake2b(&self, message: &[u8]) -> [u8; 64] {
+        self.sign(blake2b_256_hash(message).as_bytes())
+    }
+
+    /// Whether `signature` is a valid Ed25519 signature of `message` by this
+    /// account, using strict verification.
+    pub fn verify(&self, message: &[u8], signature: &[u8; 64]) -> bool {
+        Signature::from_bytes(signature)
+            .and_then(|s| self.public_key.verify_strict(message, &s))
+            .is_ok()
+    }
+
+    /// Whether `other` is the address of this account, comparing the decoded
+    /// address bytes in constant time, so that the time taken does not leak how
+    /// many leading bytes matched, e.g. when scanning many accounts.
+    ///
+    /// Returns `false` if `other` is not a valid address on the network of
+    /// this account, this check is not constant time.
+    pub fn address_eq_ct(&self, other: &str) -> bool {
+        AddressBech32Decoder::new(&self.network_id.network_definition())
+            .validate_and_decode(other)
+            .is_ok_and(|(_, bytes)| bool::from(bytes.ct_eq(&self.node_id())))
+    }
+
+    /// A stable seed derived from the `address`, for GUIs to render a consistent
+    /// avatar or gradient for this account.
+    ///
+    /// This is for visual identity ONLY, it MUST NOT be used for anything security
+    /// related, it is merely the first 8 bytes of the `blake2b_256_hash` of the address.
+    pub fn avatar_seed(&self) -> u64 {
+        let hash = blake2b_256_hash(self.address.as_bytes()).into_bytes();
+        u64::from_le_bytes(
+            hash[..8]
+                .try_into()
+                .expect("Should always be able to take 8 bytes of a 32 bytes hash."),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -98,6 +484,19 @@ impl Mnemonic24Words {
     }
 }
 
+#[cfg(test)]
+impl Account {
+    /// The account at `index` on `network_id` of `Mnemonic24Words::test_0` and
+    /// the empty passphrase, shared by the tests of the account formats.
+    pub(crate) fn test_0(network_id: &NetworkID, index: HDPathComponentValue) -> Self {
+        Self::derive_unwrap(
+            &Mnemonic24Words::test_0(),
+            "",
+            &AccountPath::new(network_id, index),
+        )
+    }
+}
+
 // Test vectors from Swift lib, see commit:
 // https://github.com/radixdlt/babylon-wallet-ios/commit/f5f654a40b2afa48820919360f2e8d2f00ebe63e
 // and from Gist
@@ -106,17 +505,32 @@ impl Mnemonic24Words {
 mod tests {
 
     use crate::prelude::*;
+    use radix_common::prelude::{AddressBech32Decoder, ComponentAddress};
     use std::ops::Range;
 
     #[test]
     fn to_string_include_private_key() {
         let path: AccountPath = "m/44H/1022H/1H/525H/1460H/0H".parse().unwrap();
-        let account = Account::derive(&Mnemonic24Words::test_0(), "", &path);
+        let account = Account::derive_unwrap(&Mnemonic24Words::test_0(), "", &path);
         let expected = "\nFactor Source ID: 6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033\nAddress: account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4\nNetwork: Mainnet\nIndex: 0\nHD Path: m/44H/1022H/1H/525H/1460H/0H\nPrivateKey: 7b21b62816c6349293abc3a8c37470f917ae621ada2eb8d5124250e83b78f7ef\nPublicKey: 6224937b15ec4017a036c0bd6999b7fa2b9c2f9452286542fd56f6a3fb6d33ed\n";
 
         assert_eq!(account.to_string_include_private_key(true), expected);
     }
 
+    #[test]
+    fn debug_redacts_private_key() {
+        let path: AccountPath = "m/44H/1022H/1H/525H/1460H/0H".parse().unwrap();
+        let account = Account::derive_unwrap(&Mnemonic24Words::test_0(), "", &path);
+        let debug = format!("{account:?}");
+        assert!(debug.contains("private_key: ***redacted***"));
+        assert!(!debug.contains(&account.private_key.to_hex()));
+        assert!(!debug.contains("bright"));
+        assert!(debug.contains("network_id: Mainnet"));
+        assert!(debug.contains("index: 0"));
+        assert!(debug.contains(&account.address));
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn test(
         mnemonic: Mnemonic24Words,
         passphrase: impl AsRef<str>,
@@ -132,7 +546,7 @@ mod tests {
         let account_path = AccountPath::new(&network_id, index);
         assert_eq!(account_path.to_string(), path); // test Display
         assert_eq!(path.parse::<AccountPath>().unwrap(), account_path); // test FromStr
-        let account = Account::derive(&mnemonic, passphrase.as_ref(), &account_path);
+        let account = Account::derive_unwrap(&mnemonic, passphrase.as_ref(), &account_path);
         assert_eq!(account.private_key.to_hex(), private_key.as_ref());
         assert_eq!(account.public_key.to_hex(), public_key.as_ref());
         assert_eq!(
@@ -145,6 +559,445 @@ mod tests {
         assert_eq!(account.index, index);
     }
 
+    #[test]
+    fn derive_rola_key() {
+        let rola_key =
+            Account::derive_rola_key(&Mnemonic24Words::test_0(), "", &NetworkID::Mainnet, 0)
+                .unwrap();
+        assert_eq!(
+            rola_key.secret.to_hex(),
+            "dc3523d2fdfa548dda9b786e418fa4ebf1d175f8a1d831be6b0212d6b31da399"
+        );
+        assert_eq!(
+            rola_key.public.to_hex(),
+            "0a6473de5d6cfdcc0c20558f6028eab1885a29a675bb4b4b45dd4d4561f2cf7b"
+        );
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        assert_ne!(rola_key.public, account.public_key);
+    }
+
+    #[test]
+    fn avatar_seed() {
+        let accounts = Account::derive_range_cancellable(
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Mainnet,
+            0..2,
+            || false,
+        )
+        .unwrap();
+        assert_eq!(accounts[0].avatar_seed(), 18368815687902692471);
+        assert_eq!(accounts[1].avatar_seed(), 9599840445001369935);
+    }
+
+    #[test]
+    fn node_id_is_decoded_address() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let decoder = AddressBech32Decoder::new(&NetworkID::Mainnet.network_definition());
+        let (_, decoded) = decoder.validate_and_decode(&account.address).unwrap();
+        assert_eq!(decoded, account.node_id().to_vec());
+        assert_eq!(
+            hex::encode(account.node_id()),
+            "51d88c94c2c86b784ea19c0e0c5e9c07daa230da4c5094a7c7d379e67eda"
+        );
+    }
+
+    #[test]
+    fn public_key_bytes_and_radix() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let hex = "6224937b15ec4017a036c0bd6999b7fa2b9c2f9452286542fd56f6a3fb6d33ed";
+        assert_eq!(account.public_key.to_hex(), hex);
+        assert_eq!(hex::encode(account.public_key_bytes()), hex);
+        assert_eq!(
+            account.public_key_radix().to_vec(),
+            account.public_key_bytes()
+        );
+        assert_eq!(
+            ComponentAddress::preallocated_account_from_public_key(&account.public_key_radix())
+                .as_node_id()
+                .0,
+            account.node_id()
+        );
+    }
+
+    #[test]
+    fn derive_from_seed_eq_derive() {
+        let mnemonic = Mnemonic24Words::test_0();
+        let seed = Seed::from_mnemonic(&mnemonic, "radix");
+        for index in 0..3 {
+            let path = AccountPath::new(&NetworkID::Mainnet, index);
+            let from_seed = Account::derive_from_seed(&seed, &path).unwrap();
+            let derived = Account::derive_unwrap(&mnemonic, "radix", &path);
+            assert_eq!(
+                from_seed.private_key.to_bytes(),
+                derived.private_key.to_bytes()
+            );
+            assert_eq!(from_seed.public_key, derived.public_key);
+            assert_eq!(from_seed.address, derived.address);
+            assert_eq!(from_seed.factor_source_id, derived.factor_source_id);
+        }
+    }
+
+    #[test]
+    fn for_each_in_range_from_seed_eq_derive() {
+        let mnemonic = Mnemonic24Words::test_0();
+        let seed = Seed::from_mnemonic(&mnemonic, "radix");
+        let mut accounts = Vec::new();
+        Account::for_each_in_range_from_seed(&seed, &NetworkID::Stokenet, 2..5, |a| {
+            accounts.push(a)
+        })
+        .unwrap();
+        assert_eq!(accounts.len(), 3);
+        for account in accounts {
+            let derived = Account::derive_unwrap(&mnemonic, "radix", &account.path);
+            assert_eq!(account.index, derived.index);
+            assert_eq!(account.address, derived.address);
+            assert_eq!(account.factor_source_id, derived.factor_source_id);
+            assert_eq!(
+                account.factor_source_id,
+                FactorSourceID::from_mnemonic(&mnemonic, "radix")
+            );
+        }
+    }
+
+    #[test]
+    fn derive_from_hex_seed_readme_account() {
+        let seed_hex = "c33bd429c56f70dd56cb64406d37e863a72cfa07804d6abfad13f1325f2be578d455213534c6bb9e05247744c3d868bcd4148adcf46d1acfb7784c54e38edc5a";
+        let path = AccountPath::new(&NetworkID::Mainnet, 0);
+        let account = Account::derive_from_hex_seed(seed_hex, &path).unwrap();
+        assert_eq!(
+            account.address,
+            "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8"
+        );
+        assert_eq!(
+            account.private_key.to_hex(),
+            "cf52dbc7bb2663223e99fb31799281b813b939440a372d0aa92eb5f5b8516003"
+        );
+        assert_eq!(
+            account.factor_source_id,
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), "radix")
+        );
+        assert_eq!(
+            Account::derive_from_hex_seed(&seed_hex[2..], &path).map(|a| a.address.clone()),
+            Err(Error::InvalidSeedHex)
+        );
+    }
+
+    #[test]
+    fn derive_malformed_path_is_err() {
+        // A non hardened account index, which `AccountPath` never allows.
+        let path = AccountPath(BIP32Path([
+            PURPOSE,
+            COINTYPE,
+            NetworkID::Mainnet.hardened_hd_component_value(),
+            EntityKind::Account.hardened_hd_component_value(),
+            KeyKind::TransactionSigning.hardened_hd_component_value(),
+            0,
+        ]));
+        let expected = Err(Error::KeyDerivationFailed(
+            "Invalid index provided".to_owned(),
+        ));
+        assert_eq!(
+            Account::derive(&Mnemonic24Words::test_0(), "", &path).map(|a| a.address.clone()),
+            expected
+        );
+        let seed = Seed::from_mnemonic(&Mnemonic24Words::test_0(), "");
+        assert_eq!(
+            Account::derive_from_seed(&seed, &path).map(|a| a.address.clone()),
+            expected
+        );
+    }
+
+    #[test]
+    fn sign_known_answer() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let message = b"Hello Radix";
+        let signature = account.sign(message);
+        assert_eq!(
+            hex::encode(signature),
+            "9a96102ee16f085bf3df6f2d916a61b8746559a35d246de8d1b9c1b0aa851a22474d85315eed541f5f446c697cb39be94e8d7e8c177cf78b8caf3612ae50450c"
+        );
+        assert!(account.verify(message, &signature));
+        assert!(!account.verify(b"Hello Olympia", &signature));
+        assert!(!account.verify(message, &[0; 64]));
+    }
+
+    #[test]
+    fn sign_prehashed_blake2b_known_answer() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let message = b"Hello Radix";
+        let signature = account.sign_prehashed_blake2b(message);
+        assert_eq!(
+            hex::encode(signature),
+            "1f558904b7edf4c7b853fc1566959d0ebf55080609efa98bb0eca94165470f8b9cbf86f628e797ec69cafcded8f7bcc7b71ebeda55a389fd74361da509e2b20e"
+        );
+        let hash = hex::decode("48f1bd08444b5e713db9e14caac2faae71836786ac94d645b00679728202a935")
+            .unwrap();
+        assert!(account.verify(&hash, &signature));
+        assert!(!account.verify(message, &signature));
+    }
+
+    #[test]
+    fn address_eq_ct() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        assert!(account.address_eq_ct(&account.address));
+        assert!(!account
+            .address_eq_ct("account_rdx129xapgx582768wrkd54mq0a8lhp8aqp5vkkc8u2jfavujktl0tatcs"));
+        // Same index on Stokenet.
+        assert!(!account.address_eq_ct(
+            "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"
+        ));
+        assert!(!account.address_eq_ct("account_rdx1"));
+    }
+
+    #[test]
+    fn find_index() {
+        let find = |address: &str, search_range| {
+            Account::find_index(
+                &Mnemonic24Words::test_0(),
+                "",
+                &NetworkID::Mainnet,
+                address,
+                search_range,
+            )
+            .unwrap()
+        };
+        let address_of_index_1 =
+            "account_rdx129xapgx582768wrkd54mq0a8lhp8aqp5vkkc8u2jfavujktl0tatcs";
+        assert_eq!(find(address_of_index_1, 0..10), Some(1));
+        assert_eq!(find(address_of_index_1, 2..10), None);
+        // Stokenet address of the same index is not found on Mainnet.
+        assert_eq!(
+            find(
+                "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8",
+                0..10
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn find_index_rejects_unhardenable_range() {
+        let find = |search_range| {
+            Account::find_index(
+                &Mnemonic24Words::test_0(),
+                "",
+                &NetworkID::Mainnet,
+                "account_rdx1",
+                search_range,
+            )
+        };
+        assert_eq!(
+            find(harden(0) - 1..harden(0) + 1),
+            Err(Error::InvalidDerivationRange {
+                start: harden(0) - 1,
+                count: 2
+            })
+        );
+        assert_eq!(
+            find(harden(0)..u32::MAX),
+            Err(Error::InvalidDerivationRange {
+                start: harden(0),
+                count: u32::MAX - harden(0)
+            })
+        );
+        assert_eq!(find(harden(0) - 1..harden(0)), Ok(None));
+    }
+
+    #[test]
+    fn derive_range_1000_accounts() {
+        let accounts =
+            Account::derive_range(&Mnemonic24Words::test_0(), "", &NetworkID::Mainnet, 0..1000)
+                .unwrap();
+        assert_eq!(accounts.len(), 1000);
+        assert!(accounts
+            .iter()
+            .enumerate()
+            .all(|(i, a)| a.index == i as EntityIndex));
+        assert_eq!(
+            accounts.first().unwrap().address,
+            "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+        );
+        assert_eq!(
+            accounts.last().unwrap().address,
+            "account_rdx12yhcv68333nlyrjh8xlek7cnqmj7e5zs7qr5fymeffkydrv2ru3avh"
+        );
+        assert_eq!(
+            accounts.last().unwrap().private_key.to_hex(),
+            "4f1f60e13be5620ee34780ae8591c231b4dc54289232f64c7117e621196914d4"
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn derive_range_par_eq_derive_range() {
+        let mnemonic = Mnemonic24Words::test_0();
+        let sequential =
+            Account::derive_range(&mnemonic, "", &NetworkID::Mainnet, 0..10_000).unwrap();
+        let parallel =
+            Account::derive_range_par(&mnemonic, "", &NetworkID::Mainnet, 0..10_000).unwrap();
+        assert_eq!(parallel.len(), 10_000);
+        assert!(sequential
+            .iter()
+            .zip(parallel.iter())
+            .all(|(s, p)| s.index == p.index
+                && s.address == p.address
+                && s.private_key.to_bytes() == p.private_key.to_bytes()));
+    }
+
+    #[test]
+    fn derive_range_cancellable_not_cancelled() {
+        let accounts = Account::derive_range_cancellable(
+            &Mnemonic24Words::test_0(),
+            "radix",
+            &NetworkID::Mainnet,
+            0..2,
+            || false,
+        )
+        .unwrap();
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8",
+                "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69"
+            ]
+        );
+    }
+
+    #[test]
+    fn shuffled_accounts_sort_by_index() {
+        let mnemonic = Mnemonic24Words::test_0();
+        let mut accounts =
+            Account::derive_range(&mnemonic, "", &NetworkID::Stokenet, 0..10).unwrap();
+        let addresses = accounts
+            .iter()
+            .map(|a| a.address.clone())
+            .collect::<Vec<_>>();
+        accounts.reverse();
+        accounts.rotate_left(4);
+        accounts.sort();
+        assert_eq!(
+            accounts.iter().map(|a| a.index).collect::<Vec<_>>(),
+            (0..10).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .collect::<Vec<_>>(),
+            addresses
+        );
+    }
+
+    #[test]
+    fn ordered_by_network_first() {
+        let mnemonic = Mnemonic24Words::test_0();
+        let mainnet =
+            Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, 5));
+        let stokenet =
+            Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Stokenet, 0));
+        assert!(mainnet < stokenet);
+        assert_eq!(
+            mainnet,
+            Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, 5))
+        );
+        assert_ne!(
+            mainnet,
+            Account::derive_unwrap(
+                &mnemonic,
+                "radix",
+                &AccountPath::new(&NetworkID::Mainnet, 5)
+            )
+        );
+    }
+
+    #[test]
+    fn derive_range_cancellable_is_ordered_by_index() {
+        let mnemonic = Mnemonic24Words::test_2();
+        let range = 1000..1010;
+
+        let mut shuffled = range.clone().rev().collect::<Vec<_>>();
+        shuffled.rotate_left(3);
+        let mut individually = shuffled
+            .into_iter()
+            .map(|i| {
+                Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, i))
+            })
+            .collect::<Vec<_>>();
+        individually.sort_by_key(|a| a.index);
+
+        let accounts = Account::derive_range_cancellable(
+            &mnemonic,
+            "",
+            &NetworkID::Mainnet,
+            range.clone(),
+            || false,
+        )
+        .unwrap();
+        assert_eq!(
+            accounts.iter().map(|a| a.index).collect::<Vec<_>>(),
+            range.collect::<Vec<_>>()
+        );
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .collect::<Vec<_>>(),
+            individually
+                .iter()
+                .map(|a| a.address.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn derive_range_cancellable_cancelled() {
+        let calls = std::cell::Cell::new(0);
+        let accounts = Account::derive_range_cancellable(
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Mainnet,
+            0..100,
+            || {
+                calls.set(calls.get() + 1);
+                calls.get() > 3
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            accounts.iter().map(|a| a.index).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn for_each_in_range() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_0());
+        let range = 5..12;
+        let mut calls = 0;
+        let mut indices = Vec::new();
+        let mut addresses = Vec::new();
+        Account::for_each_in_range(&mnemonic, "", &NetworkID::Stokenet, range.clone(), |a| {
+            calls += 1;
+            indices.push(a.index);
+            addresses.push(a.address.clone());
+        })
+        .unwrap();
+        assert_eq!(calls, 7);
+        assert_eq!(indices, range.clone().collect::<Vec<_>>());
+        let expected = Account::derive_range(&mnemonic, "", &NetworkID::Stokenet, range).unwrap();
+        assert_eq!(
+            addresses,
+            expected
+                .iter()
+                .map(|a| a.address.clone())
+                .collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn zeroize_account_private_key_is_zeroized() {
         let mnemonic = Mnemonic24Words::new([
@@ -153,7 +1006,7 @@ mod tests {
             0xff, 0xff, 0xff, 0xff,
         ]);
         let path: AccountPath = "m/44H/1022H/1H/525H/1460H/0H".parse().unwrap();
-        let mut account = Account::derive(&mnemonic, "", &path);
+        let mut account = Account::derive_unwrap(&mnemonic, "", &path);
 
         let private_key_view = &account.private_key as *const _ as *const u8;
         let private_key_range = Range { start: 0, end: 32 };
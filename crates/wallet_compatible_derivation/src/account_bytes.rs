@@ -0,0 +1,257 @@
+use crate::prelude::*;
+
+use ed25519_dalek::{PublicKey, SecretKey};
+use zeroize::Zeroizing;
+
+/// An account decoded from the compact binary layout of [`Account::to_bytes`],
+/// e.g. read from a large file of accounts in a high-throughput pipeline.
+///
+/// Unlike an [`Account`] the private key is optional, and the HD path and
+/// `FactorSourceID` are not part of the layout.
+///
+/// The private key, if any, is zeroized on drop.
+#[derive(ZeroizeOnDrop, Zeroize)]
+pub struct AccountBinaryRecord {
+    /// The network of the `address`.
+    #[zeroize(skip)]
+    pub network_id: NetworkID,
+
+    /// The value of the last HD path component, the account index.
+    pub index: HDPathComponentValue,
+
+    /// The public key of the account.
+    #[zeroize(skip)]
+    pub public_key: PublicKey,
+
+    /// The private key of the account, if it was included.
+    pub private_key: Option<SecretKey>,
+
+    /// A bech32 encoded Radix Babylon account address.
+    pub address: String,
+}
+
+impl std::fmt::Debug for AccountBinaryRecord {
+    /// Redacts the private key, if included.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountBinaryRecord")
+            .field("network_id", &self.network_id)
+            .field("index", &self.index)
+            .field("public_key", &format_args!("{}", self.public_key.to_hex()))
+            .field(
+                "private_key",
+                &self
+                    .private_key
+                    .as_ref()
+                    .map(|_| format_args!("***redacted***")),
+            )
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+/// Splits off the first `len` bytes of `bytes`, or fails if there are fewer,
+/// naming the `field` which is truncated.
+fn take<'a>(bytes: &mut &'a [u8], len: usize, field: &str) -> Result<&'a [u8]> {
+    if bytes.len() < len {
+        return Err(Error::InvalidAccountBytes(format!("truncated {field}")));
+    }
+    let (head, tail) = bytes.split_at(len);
+    *bytes = tail;
+    Ok(head)
+}
+
+impl Account {
+    /// Serializes this account in a compact binary layout, all integers little
+    /// endian:
+    ///
+    /// | bytes | field                                                      |
+    /// |-------|------------------------------------------------------------|
+    /// | 1     | network ID, see `NetworkID::discriminant`                  |
+    /// | 4     | account index                                              |
+    /// | 32    | public key                                                 |
+    /// | 1     | `1` if the private key is included, else `0`               |
+    /// | 32    | private key, only if included                              |
+    /// | 2     | length of the address, in bytes                            |
+    /// | *     | bech32 encoded address, UTF-8                              |
+    ///
+    /// Thus the length of a record is known after reading its header, letting
+    /// a consumer walk a memory-mapped file of many records. Decode it with
+    /// `Account::from_bytes`.
+    pub fn to_bytes(&self, include_private_key: bool) -> Zeroizing<Vec<u8>> {
+        let mut bytes = Zeroizing::new(Vec::with_capacity(72 + self.address.len()));
+        bytes.push(self.network_id.discriminant());
+        bytes.extend_from_slice(&self.index.to_le_bytes());
+        bytes.extend_from_slice(self.public_key.as_bytes());
+        bytes.push(include_private_key as u8);
+        if include_private_key {
+            bytes.extend_from_slice(self.private_key.as_bytes());
+        }
+        bytes.extend_from_slice(&(self.address.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(self.address.as_bytes());
+        bytes
+    }
+
+    /// Decodes an account serialized with `Account::to_bytes`, validating that
+    /// the address is that of the public key on the network, and that the
+    /// private key, if included, is that of the public key.
+    ///
+    /// Returns `Err(Error::InvalidAccountBytes)` if `bytes` is not exactly one
+    /// valid record, zeroizing any decoded private key.
+    pub fn from_bytes(bytes: &[u8]) -> Result<AccountBinaryRecord> {
+        let mut bytes = bytes;
+        let network_id = NetworkID::try_from(take(&mut bytes, 1, "network ID")?[0])?;
+        let index = HDPathComponentValue::from_le_bytes(
+            take(&mut bytes, 4, "index")?
+                .try_into()
+                .expect("Took 4 bytes."),
+        );
+        let public_key = PublicKey::from_bytes(take(&mut bytes, 32, "public key")?)
+            .map_err(|_| Error::InvalidAccountBytes("invalid public key".to_owned()))?;
+        let private_key = match take(&mut bytes, 1, "private key flag")?[0] {
+            0 => None,
+            1 => Some(
+                SecretKey::from_bytes(take(&mut bytes, 32, "private key")?)
+                    .map_err(|_| Error::InvalidAccountBytes("invalid private key".to_owned()))?,
+            ),
+            flag => {
+                return Err(Error::InvalidAccountBytes(format!(
+                    "invalid private key flag {flag}"
+                )))
+            }
+        };
+        // From here on `record` zeroizes the private key when dropped, also
+        // when returning `Err`.
+        let mut record = AccountBinaryRecord {
+            network_id,
+            index,
+            public_key,
+            private_key,
+            address: String::new(),
+        };
+        let address_len = u16::from_le_bytes(
+            take(&mut bytes, 2, "address length")?
+                .try_into()
+                .expect("Took 2 bytes."),
+        );
+        record.address = std::str::from_utf8(take(&mut bytes, address_len as usize, "address")?)
+            .map_err(|_| Error::InvalidAccountBytes("address is not UTF-8".to_owned()))?
+            .to_owned();
+        if !bytes.is_empty() {
+            return Err(Error::InvalidAccountBytes(format!(
+                "{} trailing bytes",
+                bytes.len()
+            )));
+        }
+        if derive_address(&record.public_key, &record.network_id)? != record.address {
+            return Err(Error::InvalidAccountBytes(
+                "address is not that of the public key".to_owned(),
+            ));
+        }
+        if record
+            .private_key
+            .as_ref()
+            .is_some_and(|k| PublicKey::from(k) != record.public_key)
+        {
+            return Err(Error::InvalidAccountBytes(
+                "private key is not that of the public key".to_owned(),
+            ));
+        }
+        Ok(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn roundtrip_including_private_key() {
+        let account = Account::test_0(&NetworkID::Stokenet, 1);
+        let bytes = account.to_bytes(true);
+        assert_eq!(bytes.len(), 72 + account.address.len());
+        assert_eq!(bytes[..5], [0x02, 1, 0, 0, 0]);
+        let record = Account::from_bytes(&bytes).unwrap();
+        assert_eq!(record.network_id, NetworkID::Stokenet);
+        assert_eq!(record.index, 1);
+        assert_eq!(record.public_key, account.public_key);
+        assert_eq!(
+            record.private_key.as_ref().unwrap().to_hex(),
+            account.private_key.to_hex()
+        );
+        assert_eq!(
+            record.address,
+            "account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+        );
+    }
+
+    #[test]
+    fn roundtrip_excluding_private_key() {
+        let account = Account::test_0(&NetworkID::Stokenet, 1);
+        let bytes = account.to_bytes(false);
+        assert_eq!(bytes.len(), 40 + account.address.len());
+        let record = Account::from_bytes(&bytes).unwrap();
+        assert!(record.private_key.is_none());
+        assert_eq!(record.public_key, account.public_key);
+        assert_eq!(record.address, account.address);
+        assert!(!format!("{record:?}").contains("redacted"));
+    }
+
+    #[test]
+    fn debug_redacts_private_key() {
+        let account = Account::test_0(&NetworkID::Stokenet, 1);
+        let record = Account::from_bytes(&account.to_bytes(true)).unwrap();
+        let debug = format!("{record:?}");
+        assert!(debug.contains("***redacted***"));
+        assert!(!debug.contains(&account.private_key.to_hex()));
+    }
+
+    #[test]
+    fn invalid() {
+        let bytes = Account::test_0(&NetworkID::Stokenet, 1).to_bytes(true);
+        let err = |reason: &str| Err(Error::InvalidAccountBytes(reason.to_owned()));
+
+        assert_eq!(
+            Account::from_bytes(&bytes[..bytes.len() - 1]).map(|r| r.index),
+            err("truncated address")
+        );
+        assert_eq!(
+            Account::from_bytes(&bytes[..20]).map(|r| r.index),
+            err("truncated public key")
+        );
+
+        let mut trailing = bytes.to_vec();
+        trailing.push(0);
+        assert_eq!(
+            Account::from_bytes(&trailing).map(|r| r.index),
+            err("1 trailing bytes")
+        );
+
+        let mut flag = bytes.to_vec();
+        flag[37] = 2;
+        assert_eq!(
+            Account::from_bytes(&flag).map(|r| r.index),
+            err("invalid private key flag 2")
+        );
+
+        let mut other_private_key = bytes.to_vec();
+        other_private_key[38] ^= 1;
+        assert_eq!(
+            Account::from_bytes(&other_private_key).map(|r| r.index),
+            err("private key is not that of the public key")
+        );
+
+        let mut other_network = bytes.to_vec();
+        other_network[0] = NetworkID::Mainnet.discriminant();
+        assert_eq!(
+            Account::from_bytes(&other_network).map(|r| r.index),
+            err("address is not that of the public key")
+        );
+
+        let mut unknown_network = bytes.to_vec();
+        unknown_network[0] = 0xff;
+        assert_eq!(
+            Account::from_bytes(&unknown_network).map(|r| r.index),
+            Err(Error::UnsupportedOrUnknownNetworkID(0xff))
+        );
+    }
+}
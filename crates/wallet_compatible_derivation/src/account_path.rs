@@ -2,7 +2,7 @@ use crate::prelude::*;
 
 /// A Radix Babylon [BIP-32][bip32] path used to derive accounts, for example `m/44'/1022'/1'/525'/1460'/2'`.
 ///
-/// This comes from the general derivation pattern for Radix addresses according to the [SLIP-10][slip10] 
+/// This comes from the general derivation pattern for Radix addresses according to the [SLIP-10][slip10]
 /// derivation scheme. In the [SLIP-10][slip10] derivation scheme, every level must be hardened, which
 /// is denoted by the `'` or `H` suffix. The official Radix wallet uses 6 levels:
 ///
@@ -10,8 +10,8 @@ use crate::prelude::*;
 /// m / purpose' / coin_type' / network' / entity_kind' / key_kind' / entity_index'
 /// ```
 ///
-/// The `AccountPath` struct is parametrized by Radix network id and account index, but fixes the other
-/// constants in the path as follows:
+/// The `AccountPath` struct is parametrized by Radix network id and account index, and optionally
+/// the key kind (see `new_with_key_kind`), but fixes the other constants in the path as follows:
 ///
 /// ```text
 /// m / 44' / 1022' / NETWORK_ID' / 525' / 1460' / ACCOUNT_INDEX'
@@ -27,17 +27,25 @@ use crate::prelude::*;
 /// * `key_kind` is the type of key. Possible values include:
 ///   * 1460 - Transaction Signing (the default).
 ///   * 1678 - Authentication Signing such as [ROLA][rola]. This is used if a separate key is
-///     created for ROLA and stored in account metadata. 
+///     created for ROLA and stored in account metadata.
 /// * `entity_index` is the 0-based index of the particular entity which is being derived.
 ///
-/// See `test_asciisum` for the source of the `entity_kind` and `key_kind` numbers.
+/// See `test_asciisum` for the source of the `entity_kind` and `key_kind` numbers,
+/// which are available as [`EntityKind`] and [`KeyKind`].
+///
+/// Both the `'` and the `H` notation of hardened path components are accepted
+/// when parsing, and result in equal `AccountPath`s. When formatted the path is
+/// canonicalized to the `H` notation. Sargon's securified notation, where `<n>S`
+/// means `<n + 2^30>H`, is accepted too, see `to_sargon_string`.
 ///
 /// ```
 /// extern crate wallet_compatible_derivation;
 /// use wallet_compatible_derivation::prelude::*;
 ///
-/// assert!("m/44'/1022'/1'/525'/1460'/1'".parse::<AccountPath>().is_ok());
-/// assert!("m/44H/1022H/1H/525H/1460H/1H".parse::<AccountPath>().is_ok());
+/// let apostrophe = "m/44'/1022'/1'/525'/1460'/1'".parse::<AccountPath>().unwrap();
+/// let h = "m/44H/1022H/1H/525H/1460H/1H".parse::<AccountPath>().unwrap();
+/// assert_eq!(apostrophe, h);
+/// assert_eq!(apostrophe.to_string(), "m/44H/1022H/1H/525H/1460H/1H");
 /// ```
 ///
 /// [bip32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
@@ -84,15 +92,6 @@ pub const PURPOSE: HDPathComponentValue = harden(44);
 /// merged PR: https://github.com/satoshilabs/slips/pull/1137
 pub const COINTYPE: HDPathComponentValue = harden(1022);
 
-/// The purpose of this key is to use it for Radix Accounts
-/// (as oppose to Identities - used by Personas - which has
-/// a different value).
-const ENTITY_KIND_ACCOUNT: HDPathComponentValue = harden(525);
-
-/// This key is used to control the entity - the Account, and
-/// can sign transactions and change the state of the account.
-const KEY_KIND_SIGN_TX: HDPathComponentValue = harden(1460);
-
 /// The index of an account, e.g. `0` being the first
 /// account derived for some Mnemonic at some network,
 /// and `1` being the second. This value is HARDENED
@@ -105,6 +104,11 @@ impl AccountPath {
         NetworkID::try_from(unhardened(self.0.clone().components()[Self::IDX_NETWORK_ID])).expect("Should not have been possible to instantiate an Account Path with an invalid Network ID.")
     }
 
+    /// Read the `key_kind` of this AccountPath.
+    pub fn key_kind(&self) -> KeyKind {
+        KeyKind::from_value(unhardened(self.0.clone().components()[Self::IDX_KEY_KIND])).expect("Should not have been possible to instantiate an Account Path with an invalid Key Kind.")
+    }
+
     /// Read the accounts `index` of this AccountPath.
     pub fn account_index(&self) -> HDPathComponentValue {
         unhardened(self.0.clone().components()[Self::IDX_ACCOUNT_INDEX])
@@ -132,27 +136,222 @@ impl AccountPath {
     /// `AccountPath`.
     pub(crate) const IDX_ENTITY_KIND: usize = 3;
 
-    /// The `key_kind` path component, must be `TRANSACTION_SIGNING` for
-    /// virtual account derivation.
+    /// The `key_kind` path component, `TransactionSigning` for virtual account
+    /// derivation, or `AuthenticationSigning` for ROLA keys.
     pub(crate) const IDX_KEY_KIND: usize = 4;
 
     /// The last path component, the index of the account.
     pub(crate) const IDX_ACCOUNT_INDEX: usize = 5;
 
+    /// Formats the path like `Display` does, except that an account index in the
+    /// upper half of the hardened keyspace, used for securified accounts, is
+    /// formatted in Sargon's securified notation, e.g. `"m/44H/1022H/1H/525H/1460H/0S"`
+    /// instead of `"m/44H/1022H/1H/525H/1460H/1073741824H"`.
+    pub fn to_sargon_string(&self) -> String {
+        self.0.to_sargon_string()
+    }
+
     /// Crates a new `AccountPath` given the tuple (network, index).
     pub fn new(network_id: &NetworkID, index: EntityIndex) -> Self {
+        Self::new_with_key_kind(network_id, KeyKind::TransactionSigning, index)
+    }
+
+    /// Crates a new `AccountPath` given the tuple (network, key kind, index),
+    /// e.g. `KeyKind::AuthenticationSigning` for the ROLA key of an account.
+    ///
+    /// N.B. the address of an [`Account`] is only the address of the account
+    /// at `index` if derived using `KeyKind::TransactionSigning`, see
+    /// [`Account::derive_rola_key`] for ROLA keys.
+    pub fn new_with_key_kind(
+        network_id: &NetworkID,
+        key_kind: KeyKind,
+        index: EntityIndex,
+    ) -> Self {
         let bip32_path = BIP32Path::<{ Self::DEPTH }>([
             PURPOSE,
             COINTYPE,
             network_id.hardened_hd_component_value(),
-            ENTITY_KIND_ACCOUNT,
-            KEY_KIND_SIGN_TX,
+            EntityKind::Account.hardened_hd_component_value(),
+            key_kind.hardened_hd_component_value(),
             harden(index),
         ]);
 
-        bip32_path
+        bip32_path.try_into().expect(
+            "Should have constructed a valid AccountPath from network_id, key_kind and index.",
+        )
+    }
+}
+
+/// A component of a path, both as its hardened value, as used in derivation,
+/// and as its unhardened value, as read by humans.
+#[derive(Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq)]
+pub struct NamedPathComponent {
+    /// The hardened value, e.g. `2147483692` for `44H`.
+    pub hardened: HDPathComponentValue,
+
+    /// The unhardened value, e.g. `44` for `44H`.
+    pub unhardened: HDPathComponentValue,
+}
+
+impl NamedPathComponent {
+    fn new(hardened: HDPathComponentValue) -> Self {
+        Self {
+            hardened,
+            unhardened: unhardened(hardened),
+        }
+    }
+}
+
+/// The components of an [`AccountPath`] by name, see [`AccountPath::components_named`].
+///
+/// Formatted for humans, e.g. `"Purpose: 44 (44H), Coin type: 1022 (1022H),
+/// Network: Mainnet (1H), Entity kind: Account (525H), Key kind:
+/// TransactionSigning (1460H), Index: 0"`.
+#[derive(Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq)]
+pub struct AccountPathComponents {
+    pub purpose: NamedPathComponent,
+    pub coin_type: NamedPathComponent,
+    pub network_id: NamedPathComponent,
+    pub entity_kind: NamedPathComponent,
+    pub key_kind: NamedPathComponent,
+    pub index: NamedPathComponent,
+}
+
+impl std::fmt::Display for AccountPathComponents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let named = |name: String, c: &NamedPathComponent| format!("{name} ({}H)", c.unhardened);
+        let network_id = NetworkID::try_from(self.network_id.unhardened)
+            .expect("Should only contain the valid network ID of an AccountPath.");
+        let entity_kind = EntityKind::from_value(self.entity_kind.unhardened)
+            .expect("Should only contain the valid entity kind of an AccountPath.");
+        let key_kind = KeyKind::from_value(self.key_kind.unhardened)
+            .expect("Should only contain the valid key kind of an AccountPath.");
+        write!(
+            f,
+            "Purpose: {}, Coin type: {}, Network: {}, Entity kind: {}, Key kind: {}, Index: {}",
+            named(self.purpose.unhardened.to_string(), &self.purpose),
+            named(self.coin_type.unhardened.to_string(), &self.coin_type),
+            named(network_id.to_string(), &self.network_id),
+            named(entity_kind.to_string(), &self.entity_kind),
+            named(key_kind.to_string(), &self.key_kind),
+            self.index.unhardened
+        )
+    }
+}
+
+impl AccountPath {
+    /// Returns each component of this path by name, both hardened and
+    /// unhardened, e.g. to explain the path to users.
+    pub fn components_named(&self) -> AccountPathComponents {
+        let components = self.0.components();
+        let named = |i: usize| NamedPathComponent::new(components[i]);
+        AccountPathComponents {
+            purpose: named(Self::IDX_PURPOSE),
+            coin_type: named(Self::IDX_COINTYPE),
+            network_id: named(Self::IDX_NETWORK_ID),
+            entity_kind: named(Self::IDX_ENTITY_KIND),
+            key_kind: named(Self::IDX_KEY_KIND),
+            index: named(Self::IDX_ACCOUNT_INDEX),
+        }
+    }
+}
+
+impl AccountPath {
+    /// Returns a copy of this path, with the same network and key kind, but
+    /// with the account index `index`.
+    ///
+    /// Panics if `index` is not less than `2^31`.
+    pub fn with_index(&self, index: EntityIndex) -> Self {
+        assert!(
+            !is_hardened(index),
+            "Account index must be less than 2^31, got: {index}"
+        );
+        Self::new_with_key_kind(&self.network_id(), self.key_kind(), index)
+    }
+
+    /// Returns the path of the next account, i.e. with the account index
+    /// incremented by one, or `None` if the account index is `2^31 - 1`,
+    /// the largest valid account index.
+    pub fn next(&self) -> Option<Self> {
+        Some(self.account_index() + 1)
+            .filter(|index| !is_hardened(*index))
+            .map(|index| self.with_index(index))
+    }
+}
+
+impl AccountPath {
+    /// Creates a new `AccountPath` for the account at `offset` in the `group`:th
+    /// group of accounts, where each group has `group_size` many accounts, i.e.
+    /// the account index is `group * group_size + offset`.
+    ///
+    /// Useful for wallet schemes reserving index ranges for different purposes,
+    /// e.g. with `group_size` `100` group `0` might be personal accounts, and
+    /// group `1` business accounts.
+    ///
+    /// Returns `Err` if `offset` is not less than `group_size`, or if the
+    /// account index is not less than `2^31`.
+    pub fn in_group(
+        network_id: &NetworkID,
+        group: u32,
+        offset: u32,
+        group_size: u32,
+    ) -> Result<Self> {
+        if offset >= group_size {
+            return Err(Error::AccountGroupOffsetOutOfBounds { offset, group_size });
+        }
+        group
+            .checked_mul(group_size)
+            .and_then(|start| start.checked_add(offset))
+            .filter(|index| !is_hardened(*index))
+            .map(|index| Self::new(network_id, index))
+            .ok_or(Error::AccountIndexInGroupTooLarge {
+                group,
+                offset,
+                group_size,
+            })
+    }
+}
+
+impl AccountPath {
+    /// A tolerant alternative to `FromStr`, for paths pasted from other tools,
+    /// which normalizes `s` before parsing and validating it as an `AccountPath`:
+    /// * surrounding whitespace and trailing slashes are trimmed,
+    /// * the `m/` prefix is optional,
+    /// * the `'` notation is replaced by the `H` notation.
+    ///
+    /// ```
+    /// extern crate wallet_compatible_derivation;
+    /// use wallet_compatible_derivation::prelude::*;
+    ///
+    /// let path = AccountPath::parse_loose(" 44'/1022'/1'/525'/1460'/0'/ ").unwrap();
+    /// assert_eq!(path, AccountPath::new(&NetworkID::Mainnet, 0));
+    /// ```
+    pub fn parse_loose(s: &str) -> Result<Self> {
+        let trimmed = s.trim().trim_end_matches('/').trim_end();
+        let without_prefix = trimmed.strip_prefix("m/").unwrap_or(trimmed);
+        format!("m/{}", without_prefix.replace('\'', "H")).parse()
+    }
+}
+
+impl AccountPath {
+    /// Whether `bip32` is a valid Radix Babylon account path, performing the
+    /// same checks as `TryFrom<BIP32Path>`, without creating an `AccountPath`.
+    pub fn is_account_path<const N: usize>(bip32: &BIP32Path<N>) -> bool {
+        Self::validate_account_path(bip32).is_ok()
+    }
+
+    /// Validates that `bip32` is a Radix Babylon account path, performing the
+    /// same checks as `TryFrom<BIP32Path>`, returning the reason it is not,
+    /// e.g. `Err(Error::InvalidAccountPathWrongDepth)` if `N` is not `6`.
+    pub fn validate_account_path<const N: usize>(bip32: &BIP32Path<N>) -> Result<()> {
+        let components: [HDPathComponentValue; Self::DEPTH] = bip32
+            .components()
             .try_into()
-            .expect("Should have constructed a valid AccountPath from network_id and index.")
+            .map_err(|_| Error::InvalidAccountPathWrongDepth {
+                expected: Self::DEPTH,
+                found: N,
+            })?;
+        validate_entity_path(&BIP32Path(components), EntityKind::Account)
     }
 }
 
@@ -162,51 +361,77 @@ impl TryFrom<BIP32Path<{ Self::DEPTH }>> for AccountPath {
     /// Tries to create a new `AccountPath` from a `BIP32Path`, by validating it,
     /// returning `Err` if it is invalid.
     fn try_from(value: BIP32Path<{ Self::DEPTH }>) -> Result<Self, Self::Error> {
-        if !value.clone().into_iter().all(|c| is_hardened(c)) {
-            return Err(Error::InvalidAccountPathNonHardenedPathComponent);
-        }
-        let components = value.clone().components();
+        validate_entity_path(&value, EntityKind::Account)?;
+        Ok(Self(value))
+    }
+}
 
-        if components.len() != Self::DEPTH {
-            return Err(Error::InvalidAccountPathWrongDepth {
-                expected: Self::DEPTH,
-                found: components.len(),
-            });
+/// Validates that `value` is a Radix Babylon path of an entity of kind
+/// `entity_kind`, which all share the same layout as `AccountPath`.
+pub(crate) fn validate_entity_path(
+    value: &BIP32Path<{ AccountPath::DEPTH }>,
+    entity_kind: EntityKind,
+) -> Result<()> {
+    if !value.clone().into_iter().all(is_hardened) {
+        return Err(Error::InvalidAccountPathNonHardenedPathComponent);
+    }
+    let components = value.clone().components();
+
+    if components.len() != AccountPath::DEPTH {
+        return Err(Error::InvalidAccountPathWrongDepth {
+            expected: AccountPath::DEPTH,
+            found: components.len(),
+        });
+    }
+    let assert_with = |i, f: fn(HDPathComponentValue) -> bool| {
+        if !f(components[i]) {
+            Err(Error::InvalidAccountPathInvalidValue {
+                index: i,
+                found: components[i],
+            })
+        } else {
+            Ok(())
         }
-        let assert_with = |i, f: fn(HDPathComponentValue) -> bool| {
-            if !f(components[i]) {
-                Err(Error::InvalidAccountPathInvalidValue {
-                    index: i,
-                    found: components[i],
-                })
-            } else {
-                Ok(())
-            }
-        };
-        let assert_value = |i, v| {
-            if components[i] != v {
-                Err(Error::InvalidAccountPathWrongValue {
-                    index: i,
-                    expected: v,
-                    found: components[i],
-                })
-            } else {
-                Ok(())
-            }
-        };
-        assert_value(Self::IDX_PURPOSE, PURPOSE)?;
-        assert_value(Self::IDX_COINTYPE, COINTYPE)?;
-        assert_with(Self::IDX_NETWORK_ID, |v| {
-            NetworkID::all()
-                .into_iter()
-                .map(|n| n.hardened_hd_component_value())
-                .any(|c| c == v)
-        })?;
-        assert_value(Self::IDX_ENTITY_KIND, ENTITY_KIND_ACCOUNT)?;
-        assert_value(Self::IDX_KEY_KIND, KEY_KIND_SIGN_TX)?;
-        // Nothing to validate at component index `IDX_ACCOUNT_INDEX` (5)
-        Ok(Self(value))
+    };
+    let assert_value = |i, v| {
+        if components[i] != v {
+            Err(Error::InvalidAccountPathWrongValue {
+                index: i,
+                expected: v,
+                found: components[i],
+            })
+        } else {
+            Ok(())
+        }
+    };
+    assert_value(AccountPath::IDX_PURPOSE, PURPOSE)?;
+    assert_value(AccountPath::IDX_COINTYPE, COINTYPE)?;
+    assert_with(AccountPath::IDX_NETWORK_ID, |v| {
+        NetworkID::all()
+            .into_iter()
+            .map(|n| n.hardened_hd_component_value())
+            .any(|c| c == v)
+    })?;
+    match EntityKind::from_value(unhardened(components[AccountPath::IDX_ENTITY_KIND])) {
+        Ok(found) if found != entity_kind => {
+            return Err(Error::PathIsForDifferentEntityKind {
+                expected: entity_kind,
+                found,
+            })
+        }
+        _ => {}
     }
+    assert_value(
+        AccountPath::IDX_ENTITY_KIND,
+        entity_kind.hardened_hd_component_value(),
+    )?;
+    assert_with(AccountPath::IDX_KEY_KIND, |v| {
+        KeyKind::all()
+            .into_iter()
+            .any(|k| k.hardened_hd_component_value() == v)
+    })?;
+    // Nothing to validate at component index `IDX_ACCOUNT_INDEX` (5)
+    Ok(())
 }
 
 impl FromStr for AccountPath {
@@ -218,9 +443,24 @@ impl FromStr for AccountPath {
     }
 }
 
+impl From<AccountPath> for String {
+    /// The path formatted using the `H` notation, e.g. `"m/44H/1022H/1H/525H/1460H/0H"`.
+    fn from(value: AccountPath) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<&AccountPath> for slip10::path::BIP32Path {
+    /// The path as a `slip10` crate path, for use with other SLIP-10 libraries.
+    fn from(value: &AccountPath) -> Self {
+        value.0.inner()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
+    use proptest::prelude::*;
 
     #[test]
     fn string_roundtrip() {
@@ -231,10 +471,287 @@ mod tests {
         assert_eq!(path.account_index(), 0);
     }
 
+    #[test]
+    fn components_named() {
+        let path: AccountPath = "m/44H/1022H/1H/525H/1460H/0H".parse().unwrap();
+        let components = path.components_named();
+        let named = |unhardened| NamedPathComponent {
+            hardened: harden(unhardened),
+            unhardened,
+        };
+        assert_eq!(
+            components,
+            AccountPathComponents {
+                purpose: named(44),
+                coin_type: named(1022),
+                network_id: named(1),
+                entity_kind: named(525),
+                key_kind: named(1460),
+                index: named(0),
+            }
+        );
+        assert_eq!(components.purpose.hardened, PURPOSE);
+        assert_eq!(
+            components.to_string(),
+            "Purpose: 44 (44H), Coin type: 1022 (1022H), Network: Mainnet (1H), Entity kind: Account (525H), Key kind: TransactionSigning (1460H), Index: 0"
+        );
+    }
+
+    #[test]
+    fn parse_loose_accepted_variants() {
+        let expected = AccountPath::new(&NetworkID::Stokenet, 7);
+        [
+            "m/44H/1022H/2H/525H/1460H/7H",
+            "m/44'/1022'/2'/525'/1460'/7'",
+            "44H/1022H/2H/525H/1460H/7H",
+            "44'/1022'/2'/525'/1460'/7'",
+            "m/44H/1022H/2H/525H/1460H/7H/",
+            "m/44H/1022H/2H/525H/1460H/7H//",
+            "  m/44'/1022'/2'/525'/1460'/7'/ \n",
+            "\t44'/1022H/2'/525H/1460'/7H / ",
+        ]
+        .iter()
+        .for_each(|s| assert_eq!(AccountPath::parse_loose(s).unwrap(), expected, "{s}"));
+    }
+
+    #[test]
+    fn parse_loose_is_looser_than_from_str() {
+        let s = " 44'/1022'/2'/525'/1460'/7'// ";
+        assert!(s.parse::<AccountPath>().is_err());
+        assert!(AccountPath::parse_loose(s).is_ok());
+    }
+
+    #[test]
+    fn parse_loose_wrong_depth() {
+        assert_eq!(
+            AccountPath::parse_loose("44'/1022'/2'/525'/1460'/"),
+            Err(Error::InvalidDepthOfBIP32Path {
+                expected: 6,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn securified_notation() {
+        let path: AccountPath = "m/44H/1022H/1H/525H/1460H/1S".parse().unwrap();
+        assert_eq!(path.account_index(), 1073741825);
+        assert_eq!(path.to_sargon_string(), "m/44H/1022H/1H/525H/1460H/1S");
+        assert_eq!(
+            AccountPath::new(&NetworkID::Mainnet, 1).to_sargon_string(),
+            "m/44H/1022H/1H/525H/1460H/1H"
+        );
+    }
+
+    #[test]
+    fn into_string_and_slip10_path() {
+        let s = "m/44H/1022H/1H/525H/1460H/0H";
+        let path: AccountPath = s.parse().unwrap();
+        let slip10_path: slip10::path::BIP32Path = (&path).into();
+        assert_eq!(slip10_path, path.0.inner());
+        assert_eq!(slip10_path.depth(), 6);
+        assert_eq!(String::from(path), s);
+    }
+
+    #[test]
+    fn string_roundtrip_authentication_signing() {
+        let s = "m/44H/1022H/2H/525H/1678H/3H";
+        let path: AccountPath = s.parse().unwrap();
+        assert_eq!(path.to_string(), s);
+        assert_eq!(
+            path,
+            AccountPath::new_with_key_kind(&NetworkID::Stokenet, KeyKind::AuthenticationSigning, 3)
+        );
+        assert_eq!(path.key_kind(), KeyKind::AuthenticationSigning);
+        assert_eq!(
+            AccountPath::new(&NetworkID::Stokenet, 3).key_kind(),
+            KeyKind::TransactionSigning
+        );
+    }
+
+    #[test]
+    fn unknown_key_kind_is_invalid_value() {
+        assert_eq!(
+            "m/44H/1022H/1H/525H/999H/0H".parse::<AccountPath>(),
+            Err(Error::InvalidAccountPathInvalidValue {
+                index: 4,
+                found: harden(999)
+            })
+        );
+    }
+
+    #[test]
+    fn identity_path_is_for_different_entity_kind() {
+        assert_eq!(
+            "m/44H/1022H/1H/618H/1460H/0H".parse::<AccountPath>(),
+            Err(Error::PathIsForDifferentEntityKind {
+                expected: EntityKind::Account,
+                found: EntityKind::Identity
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_entity_kind_is_wrong_value() {
+        assert_eq!(
+            "m/44H/1022H/1H/999H/1460H/0H".parse::<AccountPath>(),
+            Err(Error::InvalidAccountPathWrongValue {
+                index: 3,
+                expected: harden(525),
+                found: harden(999)
+            })
+        );
+    }
+
+    #[test]
+    fn is_account_path_valid() {
+        let path = AccountPath::new(&NetworkID::Stokenet, 7);
+        assert!(AccountPath::is_account_path(&path.0));
+        assert_eq!(AccountPath::validate_account_path(&path.0), Ok(()));
+    }
+
+    #[test]
+    fn is_account_path_wrong_depth() {
+        let olympia = OlympiaAccountPath::new(0);
+        assert!(!AccountPath::is_account_path(&olympia.0));
+        assert_eq!(
+            AccountPath::validate_account_path(&olympia.0),
+            Err(Error::InvalidAccountPathWrongDepth {
+                expected: 6,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn is_account_path_wrong_entity_kind() {
+        let identity = IdentityPath::new(&NetworkID::Mainnet, 0);
+        assert!(!AccountPath::is_account_path(&identity.0));
+        assert_eq!(
+            AccountPath::validate_account_path(&identity.0),
+            Err(Error::PathIsForDifferentEntityKind {
+                expected: EntityKind::Account,
+                found: EntityKind::Identity
+            })
+        );
+    }
+
+    #[test]
+    fn next() {
+        let path = AccountPath::new(&NetworkID::Stokenet, 0);
+        let next = path.next().unwrap();
+        assert_eq!(next, AccountPath::new(&NetworkID::Stokenet, 1));
+        assert_eq!(next.next().unwrap().account_index(), 2);
+
+        let rola =
+            AccountPath::new_with_key_kind(&NetworkID::Mainnet, KeyKind::AuthenticationSigning, 7);
+        assert_eq!(
+            rola.next().unwrap(),
+            AccountPath::new_with_key_kind(&NetworkID::Mainnet, KeyKind::AuthenticationSigning, 8)
+        );
+    }
+
+    #[test]
+    fn next_at_max_index_is_none() {
+        let max = 2u32.pow(31) - 1;
+        let path = AccountPath::new(&NetworkID::Mainnet, max - 1)
+            .next()
+            .unwrap();
+        assert_eq!(path.account_index(), max);
+        assert_eq!(path.next(), None);
+    }
+
+    #[test]
+    fn with_index() {
+        let path = AccountPath::new(&NetworkID::Stokenet, 3);
+        assert_eq!(
+            path.with_index(999),
+            AccountPath::new(&NetworkID::Stokenet, 999)
+        );
+        assert_eq!(path.account_index(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account index must be less than 2^31")]
+    fn with_index_hardened_panics() {
+        AccountPath::new(&NetworkID::Mainnet, 0).with_index(2u32.pow(31));
+    }
+
+    #[test]
+    fn in_group() {
+        let path = AccountPath::in_group(&NetworkID::Stokenet, 1, 5, 100).unwrap();
+        assert_eq!(path, AccountPath::new(&NetworkID::Stokenet, 105));
+        assert_eq!(
+            AccountPath::in_group(&NetworkID::Mainnet, 0, 100, 100),
+            Err(Error::AccountGroupOffsetOutOfBounds {
+                offset: 100,
+                group_size: 100
+            })
+        );
+    }
+
+    #[test]
+    fn in_group_too_large() {
+        let max = 2u32.pow(31) - 1;
+        assert_eq!(
+            AccountPath::in_group(&NetworkID::Mainnet, max / 100, max % 100, 100)
+                .unwrap()
+                .account_index(),
+            max
+        );
+        let err = |group, offset, group_size| {
+            Err(Error::AccountIndexInGroupTooLarge {
+                group,
+                offset,
+                group_size,
+            })
+        };
+        // index == 2^31
+        assert_eq!(
+            AccountPath::in_group(&NetworkID::Mainnet, 2u32.pow(30), 0, 2),
+            err(2u32.pow(30), 0, 2)
+        );
+        // overflows u32
+        assert_eq!(
+            AccountPath::in_group(&NetworkID::Mainnet, u32::MAX, 0, 2),
+            err(u32::MAX, 0, 2)
+        );
+        assert_eq!(
+            AccountPath::in_group(&NetworkID::Mainnet, 1, u32::MAX - 1, u32::MAX),
+            err(1, u32::MAX - 1, u32::MAX)
+        );
+    }
+
+    #[test]
+    fn apostrophe_and_h_notation_are_equal() {
+        let apostrophe: AccountPath = "m/44'/1022'/2'/525'/1460'/1073741824'".parse().unwrap();
+        let h: AccountPath = "m/44H/1022H/2H/525H/1460H/1073741824H".parse().unwrap();
+        assert_eq!(apostrophe, h);
+        assert_eq!(apostrophe.to_string(), h.to_string());
+        assert_eq!(
+            apostrophe.to_string(),
+            "m/44H/1022H/2H/525H/1460H/1073741824H"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn string_roundtrip_any_network_any_index(
+            network_id in proptest::sample::select(NetworkID::all()),
+            index in 0..2u32.pow(31)
+        ) {
+            let path = AccountPath::new(&network_id, index);
+            let s = path.to_string();
+            let parsed: AccountPath = s.parse().unwrap();
+            prop_assert_eq!(&parsed, &path);
+            prop_assert_eq!(parsed.network_id(), network_id);
+            prop_assert_eq!(parsed.account_index(), index);
+        }
+    }
 
     #[test]
     fn test_asciisum() {
-        let ascii_sum = |s: &str| s.chars().into_iter().fold(0, |acc, c| acc + c as u64);
+        let ascii_sum = |s: &str| s.chars().fold(0, |acc, c| acc + c as u64);
         assert_eq!(ascii_sum("ACCOUNT"), 525);
         assert_eq!(ascii_sum("IDENTITY"), 618);
         assert_eq!(ascii_sum("TRANSACTION_SIGNING"), 1460);
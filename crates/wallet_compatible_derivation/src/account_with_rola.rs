@@ -0,0 +1,135 @@
+use crate::prelude::*;
+
+use ed25519_dalek::{PublicKey, SecretKey};
+
+/// An [`Account`] together with its [ROLA][rola] authentication signing key
+/// pair, i.e. the key pair at the same network and index but with key kind
+/// `KeyKind::AuthenticationSigning`, e.g. for onboarding to ROLA enabled dApps.
+///
+/// Both private keys are zeroized on drop.
+///
+/// See [`Account::derive_with_rola`].
+///
+/// [rola]: https://docs.radixdlt.com/docs/rola-radix-off-ledger-auth
+#[derive(ZeroizeOnDrop, Zeroize)]
+pub struct AccountWithRola {
+    /// The account, controlled by the transaction signing key.
+    pub account: Account,
+
+    /// The private key used to sign ROLA challenges of `account`.
+    pub rola_private_key: SecretKey,
+
+    /// The public key of `rola_private_key`, which dApps verify ROLA proofs
+    /// against, stored in the metadata of `account`.
+    #[zeroize(skip)]
+    pub rola_public_key: PublicKey,
+}
+
+impl std::fmt::Debug for AccountWithRola {
+    /// Redacts both private keys.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccountWithRola")
+            .field("account", &self.account)
+            .field("rola_private_key", &format_args!("***redacted***"))
+            .field(
+                "rola_public_key",
+                &format_args!("{}", self.rola_public_key.to_hex()),
+            )
+            .finish()
+    }
+}
+
+impl Account {
+    /// Derives the [`Account`] at `path` using the `mnemonic` and BIP-39
+    /// `passphrase` (can be the empty string), together with its ROLA key pair,
+    /// at the path on the same network and with the same index as `path`, but
+    /// with key kind `KeyKind::AuthenticationSigning`, see `derive_rola_key`.
+    ///
+    /// Computes the BIP-39 seed only once.
+    pub fn derive_with_rola(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        path: &AccountPath,
+    ) -> Result<AccountWithRola> {
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        let account = Self::derive_from_seed(&seed, path)?;
+        let rola_path = AccountPath::new_with_key_kind(
+            &path.network_id(),
+            KeyKind::AuthenticationSigning,
+            path.account_index(),
+        );
+        let (rola_private_key, rola_public_key) = Ed25519Scheme::derive(&seed, &rola_path.0)?;
+        Ok(AccountWithRola {
+            account,
+            rola_private_key,
+            rola_public_key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn rola_key_matches_derived_at_authentication_signing_path() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_0());
+        let path = AccountPath::new(&NetworkID::Stokenet, 1);
+        let sut = Account::derive_with_rola(&mnemonic, "", &path).unwrap();
+
+        let rola_path =
+            AccountPath::new_with_key_kind(&NetworkID::Stokenet, KeyKind::AuthenticationSigning, 1);
+        assert_eq!(rola_path.to_string(), "m/44H/1022H/2H/525H/1678H/1H");
+        let seed = Seed::from_mnemonic(&mnemonic, "");
+        let (private_key, public_key) = Ed25519Scheme::derive(&seed, &rola_path.0).unwrap();
+        assert_eq!(sut.rola_public_key, public_key);
+        assert_eq!(sut.rola_private_key.to_hex(), private_key.to_hex());
+
+        assert_eq!(
+            sut.account.address,
+            "account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+        );
+        assert_ne!(sut.rola_public_key, sut.account.public_key);
+    }
+
+    #[test]
+    fn rola_key_matches_derive_rola_key() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_0());
+        let sut =
+            Account::derive_with_rola(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, 0))
+                .unwrap();
+        let rola_key = Account::derive_rola_key(&mnemonic, "", &NetworkID::Mainnet, 0).unwrap();
+        assert_eq!(sut.rola_public_key, rola_key.public);
+        assert_eq!(
+            sut.rola_public_key.to_hex(),
+            "0a6473de5d6cfdcc0c20558f6028eab1885a29a675bb4b4b45dd4d4561f2cf7b"
+        );
+    }
+
+    #[test]
+    fn debug_redacts_private_keys() {
+        let sut = Account::derive_with_rola(
+            &Mnemonic24Words::test_0(),
+            "",
+            &AccountPath::new(&NetworkID::Mainnet, 0),
+        )
+        .unwrap();
+        let debug = format!("{sut:?}");
+        assert!(!debug.contains(&sut.rola_private_key.to_hex()));
+        assert!(!debug.contains(&sut.account.private_key.to_hex()));
+        assert!(debug.contains(&sut.rola_public_key.to_hex()));
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut sut = Account::derive_with_rola(
+            &Mnemonic24Words::test_0(),
+            "",
+            &AccountPath::new(&NetworkID::Mainnet, 0),
+        )
+        .unwrap();
+        sut.zeroize();
+        assert!(sut.account.is_zeroized());
+        assert_eq!(sut.rola_private_key.to_bytes(), [0; 32]);
+    }
+}
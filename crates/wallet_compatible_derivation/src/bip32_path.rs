@@ -19,7 +19,14 @@ impl<const N: usize> TryFrom<slip10::path::BIP32Path> for BIP32Path<N> {
                 expected: N,
                 found: *depth,
             })
-            .map(|cs| Self(cs))
+            .map(Self)
+    }
+}
+
+impl<const N: usize> From<[HDPathComponentValue; N]> for BIP32Path<N> {
+    /// A path of the components `value`, use `harden` for hardened components.
+    fn from(value: [HDPathComponentValue; N]) -> Self {
+        Self(value)
     }
 }
 
@@ -37,11 +44,29 @@ impl<const N: usize> BIP32Path<N> {
     /// level with `/`, and printing `H` if it was hardened, as per BIP-32 standard
     /// notation.
     pub fn to_bip32_string(&self) -> String {
+        self.to_string_securified_notation(false)
+    }
+
+    /// Like `to_bip32_string` but formats hardened components in the upper half
+    /// of the hardened keyspace, which Sargon uses for securified entities, in
+    /// Sargon's securified notation, e.g. `1073741825H` as `1S`.
+    pub fn to_sargon_string(&self) -> String {
+        self.to_string_securified_notation(true)
+    }
+
+    fn to_string_securified_notation(&self, securified_notation: bool) -> String {
         let tail = self
             .clone()
             .into_iter()
-            .map(|c| unhardened(c))
-            .map(|v| format!("{}H", v))
+            .map(|v| {
+                if !is_hardened(v) {
+                    format!("{}", v)
+                } else if securified_notation && is_securified(v) {
+                    format!("{}S", unhardened(v) - SECURIFIED_OFFSET)
+                } else {
+                    format!("{}H", unhardened(v))
+                }
+            })
             .join("/");
         format!("m/{}", tail)
     }
@@ -51,6 +76,38 @@ impl<const N: usize> BIP32Path<N> {
             .expect("Should only have valid BIP-32 path")
     }
 
+    /// The path as a `bip32` crate `DerivationPath`, used for secp256k1 derivation.
+    pub(crate) fn derivation_path(&self) -> bip32::DerivationPath {
+        let mut path = bip32::DerivationPath::default();
+        path.extend(self.clone().into_iter().map(bip32::ChildNumber));
+        path
+    }
+
+    /// A new path of depth `M` with `component` appended to this path, e.g.
+    /// `m/44H/1022H` with `365H` appended is `m/44H/1022H/365H`.
+    ///
+    /// `M` must be `N + 1`, which is checked at compile time, since expressions
+    /// of const generics, such as `N + 1`, cannot be used in the return type on
+    /// stable Rust. `M` is usually inferred from the annotated type.
+    pub fn append<const M: usize>(&self, component: HDPathComponentValue) -> BIP32Path<M> {
+        const { assert!(M == N + 1, "`append` must return a path of depth `N + 1`") };
+        let mut components = [0; M];
+        components[..N].copy_from_slice(&self.0);
+        components[N] = component;
+        BIP32Path(components)
+    }
+
+    /// A new path of depth `M` without the last component of this path, e.g.
+    /// the parent of `m/44H/1022H/365H` is `m/44H/1022H`.
+    ///
+    /// `M` must be `N - 1`, which is checked at compile time, see `append`.
+    pub fn parent<const M: usize>(&self) -> BIP32Path<M> {
+        const { assert!(M + 1 == N, "`parent` must return a path of depth `N - 1`") };
+        let mut components = [0; M];
+        components.copy_from_slice(&self.0[..M]);
+        BIP32Path(components)
+    }
+
     /// Returns each path component, layer, of the BIP-32 path as a vector.
     pub fn components(&self) -> Vec<HDPathComponentValue> {
         self.clone()
@@ -63,15 +120,63 @@ impl<const N: usize> FromStr for BIP32Path<N> {
     type Err = crate::Error;
 
     /// Tries to parse a BIP-32 string into a BIP32Path.
+    ///
+    /// Also accepts Sargon's securified notation, where `<n>S` means
+    /// `<n + 2^30>H`, e.g. `m/44H/1022H/1H/525H/1460H/0S`.
+    ///
+    /// Hardened components must all use the same marker, either `H` or `'`,
+    /// mixed notation, e.g. `m/44'/1022H`, is rejected, since it is likely a
+    /// copy-paste error. Securified components, `<n>S`, can be combined with
+    /// either marker.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        slip10::path::BIP32Path::from_str(s)
-            .map_err(|_| Error::InvalidBIP32Path(s.to_string()))
+        check_consistent_hardening_marker(s)
+            .and_then(|_| expand_securified_notation(s))
+            .and_then(|e| {
+                slip10::path::BIP32Path::from_str(&e)
+                    .map_err(|_| Error::InvalidBIP32Path(s.to_string()))
+            })
             .and_then(|p| p.try_into())
     }
 }
 
-/// The `slip10::path::BIP32Path` type does not impl Iterator, 
-/// nor does it expose a `as_vec` method, so we need to build 
+/// Fails if the path `s` has both components hardened with `H` and with `'`.
+fn check_consistent_hardening_marker(s: &str) -> Result<()> {
+    let is_marked_with = |marker| s.split('/').any(|c| c.ends_with(marker));
+    if is_marked_with('H') && is_marked_with('\'') {
+        Err(Error::InvalidBIP32Path(s.to_string()))
+    } else {
+        Ok(())
+    }
+}
+
+/// The offset of the upper half of the hardened keyspace, `2^30`, which Sargon
+/// uses for securified entities, denoted with the `S` suffix.
+pub(crate) const SECURIFIED_OFFSET: HDPathComponentValue = 1 << 30;
+
+/// Whether the hardened `value` is in the securified half of the hardened keyspace.
+fn is_securified(value: HDPathComponentValue) -> bool {
+    unhardened(value) >= SECURIFIED_OFFSET
+}
+
+/// Replaces every component in Sargon's securified notation, `<n>S`, of the
+/// path `s` with `<n + 2^30>H`.
+fn expand_securified_notation(s: &str) -> Result<String> {
+    s.split('/')
+        .map(|c| match c.strip_suffix('S') {
+            None => Ok(c.to_owned()),
+            Some(n) => n
+                .parse::<HDPathComponentValue>()
+                .ok()
+                .filter(|n| *n < SECURIFIED_OFFSET)
+                .map(|n| format!("{}H", n + SECURIFIED_OFFSET))
+                .ok_or_else(|| Error::InvalidBIP32Path(s.to_string())),
+        })
+        .collect::<Result<Vec<_>>>()
+        .map(|components| components.join("/"))
+}
+
+/// The `slip10::path::BIP32Path` type does not impl Iterator,
+/// nor does it expose a `as_vec` method, so we need to build
 /// that ourselves.
 fn components_from(path: &slip10::path::BIP32Path) -> Vec<u32> {
     let mut vec = Vec::<HDPathComponentValue>::new();
@@ -89,7 +194,7 @@ impl<const N: usize> IntoIterator for BIP32Path<N> {
     type IntoIter = std::array::IntoIter<Self::Item, N>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.clone().into_iter()
+        self.0.into_iter()
     }
 }
 
@@ -97,6 +202,7 @@ impl<const N: usize> IntoIterator for BIP32Path<N> {
 mod tests {
 
     use crate::prelude::*;
+    #[allow(clippy::upper_case_acronyms)]
     type SUT = BIP32Path<6>;
 
     #[test]
@@ -106,6 +212,13 @@ mod tests {
         assert_eq!(path.to_string(), s);
     }
 
+    #[test]
+    fn string_roundtrip_non_hardened() {
+        let s = "m/44H/1022H/0H/0/5";
+        let path: BIP32Path<5> = s.parse().unwrap();
+        assert_eq!(path.to_string(), s);
+    }
+
     #[test]
     fn inner_roundtrip() {
         let s = "m/44H/1022H/1H/525H/1460H/0H";
@@ -115,4 +228,98 @@ mod tests {
         let path2: SUT = i.parse().unwrap();
         assert_eq!(path2, path);
     }
+
+    #[test]
+    fn consistent_apostrophe_notation() {
+        let path: SUT = "m/44'/1022'/1'/525'/1460'/0'".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44H/1022H/1H/525H/1460H/0H");
+    }
+
+    #[test]
+    fn consistent_h_notation() {
+        let path: SUT = "m/44H/1022H/1H/525H/1460H/0H".parse().unwrap();
+        assert_eq!(path.inner().to_string(), "m/44'/1022'/1'/525'/1460'/0'");
+    }
+
+    #[test]
+    fn mixed_notation_is_rejected() {
+        for s in [
+            "m/44'/1022H/1'/525'/1460'/0'",
+            "m/44H/1022H/1H/525H/1460H/0'",
+        ] {
+            assert_eq!(s.parse::<SUT>(), Err(Error::InvalidBIP32Path(s.to_owned())));
+        }
+        assert_eq!(
+            "m/44'/1022'/1'/525'/1460'/0S"
+                .parse::<SUT>()
+                .unwrap()
+                .to_sargon_string(),
+            "m/44H/1022H/1H/525H/1460H/0S"
+        );
+    }
+
+    #[test]
+    fn append() {
+        let path: BIP32Path<3> = BIP32Path::from([harden(44)])
+            .append::<2>(harden(1022))
+            .append(harden(365));
+        assert_eq!(path, "m/44H/1022H/365H".parse().unwrap());
+        assert_eq!(path.to_string(), "m/44H/1022H/365H");
+    }
+
+    #[test]
+    fn append_non_hardened() {
+        let path: BIP32Path<2> = "m/44H/1022H".parse().unwrap();
+        let path: BIP32Path<3> = path.append(7);
+        assert_eq!(path.to_string(), "m/44H/1022H/7");
+    }
+
+    #[test]
+    fn parent() {
+        let path: SUT = "m/44H/1022H/1H/525H/1460H/0H".parse().unwrap();
+        let parent: BIP32Path<5> = path.parent();
+        assert_eq!(parent.to_string(), "m/44H/1022H/1H/525H/1460H");
+        let grandparent: BIP32Path<4> = parent.parent();
+        assert_eq!(grandparent.to_string(), "m/44H/1022H/1H/525H");
+        let again: SUT = parent.append(harden(0));
+        assert_eq!(again, path);
+    }
+
+    #[test]
+    fn securified_notation() {
+        let path: SUT = "m/44H/1022H/1H/525H/1460H/0S".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44H/1022H/1H/525H/1460H/1073741824H");
+        assert_eq!(path.to_sargon_string(), "m/44H/1022H/1H/525H/1460H/0S");
+
+        let path: SUT = "m/44H/1022H/1H/525H/1460H/1S".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44H/1022H/1H/525H/1460H/1073741825H");
+        assert_eq!(
+            path,
+            "m/44H/1022H/1H/525H/1460H/1073741825H".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn securified_notation_boundary() {
+        // Last index of the unsecurified half is never formatted as securified.
+        let path: SUT = "m/44H/1022H/1H/525H/1460H/1073741823H".parse().unwrap();
+        assert_eq!(
+            path.to_sargon_string(),
+            "m/44H/1022H/1H/525H/1460H/1073741823H"
+        );
+        // Last index of the securified half.
+        let path: SUT = "m/44H/1022H/1H/525H/1460H/1073741823S".parse().unwrap();
+        assert_eq!(path.to_string(), "m/44H/1022H/1H/525H/1460H/2147483647H");
+        assert_eq!(
+            path.to_sargon_string(),
+            "m/44H/1022H/1H/525H/1460H/1073741823S"
+        );
+        // `2^30` with securified offset exceeds the hardened keyspace.
+        assert_eq!(
+            "m/44H/1022H/1H/525H/1460H/1073741824S".parse::<SUT>(),
+            Err(Error::InvalidBIP32Path(
+                "m/44H/1022H/1H/525H/1460H/1073741824S".to_owned()
+            ))
+        );
+    }
 }
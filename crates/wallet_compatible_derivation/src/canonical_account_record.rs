@@ -0,0 +1,165 @@
+use crate::prelude::*;
+
+/// The fields of an [`Account`] as they are output, in machine readable
+/// formats, e.g. CSV and JSON, the single source of truth for their values
+/// and order, see [`CanonicalAccountRecord::fields`].
+///
+/// Created with `From<&Account>`, which includes the private key, remove it
+/// with `include_private_key(false)`, or with [`Account::to_canonical_record`],
+/// which excludes it.
+#[derive(Zeroize, ZeroizeOnDrop, Clone, PartialEq, Eq)]
+pub struct CanonicalAccountRecord {
+    /// The value of the last HD path component, the account index.
+    pub index: HDPathComponentValue,
+
+    /// A bech32 encoded Radix Babylon account address.
+    pub address: String,
+
+    /// The network of the `address`.
+    #[zeroize(skip)]
+    pub network_id: NetworkID,
+
+    /// The hex of the 32 bytes Ed25519 public key.
+    pub public_key: String,
+
+    /// The HD path which was used to derive the keys.
+    pub path: AccountPath,
+
+    /// ID of the mnemonic and passphrase the account was derived with.
+    pub factor_source_id: FactorSourceID,
+
+    /// The hex of the 32 bytes Ed25519 private key, if included.
+    pub private_key: Option<String>,
+}
+
+impl From<&Account> for CanonicalAccountRecord {
+    /// Includes the private key, see `include_private_key`.
+    fn from(value: &Account) -> Self {
+        Self {
+            index: value.index,
+            address: value.address.clone(),
+            network_id: value.network_id.clone(),
+            public_key: value.public_key.to_hex(),
+            path: value.path.clone(),
+            factor_source_id: value.factor_source_id.clone(),
+            private_key: Some(value.private_key.to_hex()),
+        }
+    }
+}
+
+impl std::fmt::Debug for CanonicalAccountRecord {
+    /// Redacts the private key, if included.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CanonicalAccountRecord")
+            .field("index", &self.index)
+            .field("address", &self.address)
+            .field("network_id", &self.network_id)
+            .field("public_key", &self.public_key)
+            .field("path", &self.path)
+            .field("factor_source_id", &self.factor_source_id)
+            .field(
+                "private_key",
+                &self
+                    .private_key
+                    .as_ref()
+                    .map(|_| format_args!("***redacted***")),
+            )
+            .finish()
+    }
+}
+
+impl CanonicalAccountRecord {
+    /// Keeps the private key only if `include_private_key` is `true`, else
+    /// zeroizes and removes it.
+    pub fn include_private_key(mut self, include_private_key: bool) -> Self {
+        if !include_private_key {
+            self.private_key.zeroize();
+            self.private_key = None;
+        }
+        self
+    }
+
+    /// The names and values of all fields, in the canonical order, which all
+    /// formats use, the private key last and only if included.
+    ///
+    /// None of the values can contain a comma, quote, backslash or control
+    /// character, thus no format needs quoting or escaping. The values MUST be
+    /// zeroized by the caller if the private key is included.
+    pub fn fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![
+            ("index", self.index.to_string()),
+            ("address", self.address.clone()),
+            ("network", self.network_id.to_string()),
+            ("public_key", self.public_key.clone()),
+            ("path", self.path.to_string()),
+            ("factor_source_id", self.factor_source_id.to_string()),
+        ];
+        if let Some(private_key) = &self.private_key {
+            fields.push(("private_key", private_key.clone()));
+        }
+        fields
+    }
+}
+
+impl Account {
+    /// The [`CanonicalAccountRecord`] of this account, without its private key.
+    pub fn to_canonical_record(&self) -> CanonicalAccountRecord {
+        CanonicalAccountRecord::from(self).include_private_key(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn fields_match_to_string_include_private_key() {
+        let account = Account::test_0(&NetworkID::Stokenet, 1);
+        let record = CanonicalAccountRecord::from(&account);
+        let text = account.to_string_include_private_key(true);
+        for (label, value) in [
+            ("Index", record.index.to_string()),
+            ("Address", record.address.clone()),
+            ("Network", record.network_id.to_string()),
+            ("PublicKey", record.public_key.clone()),
+            ("HD Path", record.path.to_string()),
+            ("Factor Source ID", record.factor_source_id.to_string()),
+            ("PrivateKey", record.private_key.clone().unwrap()),
+        ] {
+            assert!(
+                text.contains(&format!("\n{label}: {value}\n")),
+                "{label}: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn to_canonical_record_excludes_private_key() {
+        let account = Account::test_0(&NetworkID::Stokenet, 1);
+        let record = account.to_canonical_record();
+        assert_eq!(record.private_key, None);
+        assert_eq!(
+            record,
+            CanonicalAccountRecord::from(&account).include_private_key(false)
+        );
+        assert_eq!(
+            record.fields().iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+            [
+                "index",
+                "address",
+                "network",
+                "public_key",
+                "path",
+                "factor_source_id"
+            ]
+        );
+    }
+
+    #[test]
+    fn debug_redacts_private_key() {
+        let account = Account::test_0(&NetworkID::Stokenet, 1);
+        let debug = format!("{:?}", CanonicalAccountRecord::from(&account));
+        assert!(debug.contains("***redacted***"));
+        assert!(!debug.contains(&account.private_key.to_hex()));
+    }
+}
@@ -0,0 +1,203 @@
+//! A C ABI for embedding account derivation in e.g. iOS and Android wallets,
+//! declared in the header `include/wallet_compatible_derivation.h`, which is
+//! regenerated by the build script when the `cffi` feature is enabled and the
+//! `WCD_UPDATE_C_HEADER` environment variable is set.
+//!
+//! The caller owns all memory passed to these functions, and MUST call
+//! `wcd_zeroize_account` on every `WcdAccount` once done with it.
+
+use crate::prelude::*;
+use std::ffi::{c_char, CStr};
+
+/// The capacity of `WcdAccount::address`, enough for the longest account address
+/// of any supported network, including the terminating NUL.
+pub const WCD_ADDRESS_CAPACITY: usize = 128;
+
+/// The result of calling a `wcd_` function, `WcdOk` on success.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WcdResult {
+    WcdOk = 0,
+    WcdNullPointer = 1,
+    WcdInvalidUtf8 = 2,
+    WcdInvalidMnemonic = 3,
+    WcdUnknownNetworkID = 4,
+    WcdInvalidIndex = 5,
+    WcdDerivationFailed = 6,
+}
+
+/// A derived account, with fixed size buffers so that it can be allocated by
+/// the caller, e.g. on the stack.
+#[repr(C)]
+#[derive(Zeroize)]
+pub struct WcdAccount {
+    /// The NUL terminated bech32m encoded address of the account.
+    pub address: [c_char; WCD_ADDRESS_CAPACITY],
+    /// The Ed25519 public key of the account.
+    pub public_key: [u8; 32],
+    /// The Ed25519 private key of the account, all zeroes unless
+    /// `has_private_key` is `true`.
+    pub private_key: [u8; 32],
+    /// If `private_key` was filled in.
+    pub has_private_key: bool,
+    /// The index of the account.
+    pub index: u32,
+    /// The discriminant of the network of the account, e.g. `1` for Mainnet.
+    pub network_id: u8,
+}
+
+/// Derives the account at `index` on the network with discriminant `network_id`
+/// using the NUL terminated UTF-8 `mnemonic` and BIP-39 `passphrase` (can be the
+/// empty string), writing it to `out`. The shorthands of the test mnemonics,
+/// e.g. `"__test_0"`, are not accepted. The private key is only written if
+/// `include_private_key` is `true`.
+///
+/// `out` is only written to if `WcdOk` is returned.
+///
+/// # Safety
+///
+/// `mnemonic` and `passphrase` MUST be NUL terminated strings, and `out` MUST
+/// point to memory valid for writing a `WcdAccount`, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn wcd_derive_account(
+    mnemonic: *const c_char,
+    passphrase: *const c_char,
+    network_id: u8,
+    index: u32,
+    include_private_key: bool,
+    out: *mut WcdAccount,
+) -> WcdResult {
+    if mnemonic.is_null() || passphrase.is_null() || out.is_null() {
+        return WcdResult::WcdNullPointer;
+    }
+    let (Ok(mnemonic), Ok(passphrase)) = (
+        CStr::from_ptr(mnemonic).to_str(),
+        CStr::from_ptr(passphrase).to_str(),
+    ) else {
+        return WcdResult::WcdInvalidUtf8;
+    };
+    let Ok(mnemonic) = Mnemonic::parse_phrase(mnemonic) else {
+        return WcdResult::WcdInvalidMnemonic;
+    };
+    let Ok(network_id) = NetworkID::try_from(network_id) else {
+        return WcdResult::WcdUnknownNetworkID;
+    };
+    if is_hardened(index) {
+        return WcdResult::WcdInvalidIndex;
+    }
+    let Ok(account) = Account::derive(&mnemonic, passphrase, &AccountPath::new(&network_id, index))
+    else {
+        return WcdResult::WcdDerivationFailed;
+    };
+
+    let out = &mut *out;
+    out.zeroize();
+    for (c, b) in out.address.iter_mut().zip(account.address.bytes()) {
+        *c = b as c_char;
+    }
+    out.public_key = account.public_key.to_bytes();
+    if include_private_key {
+        out.private_key = account.private_key.to_bytes();
+    }
+    out.has_private_key = include_private_key;
+    out.index = account.index;
+    out.network_id = account.network_id.discriminant();
+    WcdResult::WcdOk
+}
+
+/// Zeroizes all fields of `account`, including the private key, does nothing
+/// if `account` is null.
+///
+/// # Safety
+///
+/// `account` MUST point to a valid `WcdAccount`, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn wcd_zeroize_account(account: *mut WcdAccount) {
+    if let Some(account) = account.as_mut() {
+        account.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{ffi::CString, mem::MaybeUninit, ptr};
+
+    fn test_0() -> String {
+        Mnemonic24Words::test_0().phrase()
+    }
+
+    fn derive(
+        mnemonic: &str,
+        network_id: u8,
+        index: u32,
+        include_private_key: bool,
+    ) -> (WcdResult, WcdAccount) {
+        let mnemonic = CString::new(mnemonic).unwrap();
+        let passphrase = CString::new("").unwrap();
+        let mut out = unsafe { MaybeUninit::<WcdAccount>::zeroed().assume_init() };
+        let result = unsafe {
+            wcd_derive_account(
+                mnemonic.as_ptr(),
+                passphrase.as_ptr(),
+                network_id,
+                index,
+                include_private_key,
+                &mut out,
+            )
+        };
+        (result, out)
+    }
+
+    #[test]
+    fn derive_account_roundtrip() {
+        let (result, mut account) = derive(&test_0(), 1, 0, true);
+        assert_eq!(result, WcdResult::WcdOk);
+        let address = unsafe { CStr::from_ptr(account.address.as_ptr()) };
+        assert_eq!(
+            address.to_str().unwrap(),
+            "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+        );
+        assert_eq!(
+            hex::encode(account.private_key),
+            "7b21b62816c6349293abc3a8c37470f917ae621ada2eb8d5124250e83b78f7ef"
+        );
+        assert!(account.has_private_key);
+        assert_eq!(account.network_id, 1);
+
+        unsafe { wcd_zeroize_account(&mut account) };
+        assert_eq!(account.private_key, [0; 32]);
+        assert_eq!(account.public_key, [0; 32]);
+        assert!(account.address.iter().all(|c| *c == 0));
+    }
+
+    #[test]
+    fn derive_account_excludes_private_key() {
+        let (result, account) = derive(&test_0(), 2, 0, false);
+        assert_eq!(result, WcdResult::WcdOk);
+        assert_eq!(account.private_key, [0; 32]);
+        assert!(!account.has_private_key);
+    }
+
+    #[test]
+    fn derive_account_errors() {
+        assert_eq!(derive("zoo", 1, 0, false).0, WcdResult::WcdInvalidMnemonic);
+        assert_eq!(
+            derive("__test_0", 1, 0, false).0,
+            WcdResult::WcdInvalidMnemonic
+        );
+        assert_eq!(
+            derive(&test_0(), 0x21, 0, false).0,
+            WcdResult::WcdUnknownNetworkID
+        );
+        assert_eq!(
+            derive(&test_0(), 1, 1 << 31, false).0,
+            WcdResult::WcdInvalidIndex
+        );
+        assert_eq!(
+            unsafe { wcd_derive_account(ptr::null(), ptr::null(), 1, 0, false, ptr::null_mut()) },
+            WcdResult::WcdNullPointer
+        );
+        unsafe { wcd_zeroize_account(ptr::null_mut()) };
+    }
+}
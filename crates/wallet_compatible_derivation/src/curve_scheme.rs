@@ -0,0 +1,123 @@
+use crate::prelude::*;
+
+use radix_common::prelude::{AddressBech32Encoder, ComponentAddress, Secp256k1PublicKey};
+
+/// An elliptic curve used to derive hierarchical deterministic key pairs from a
+/// BIP-39 seed, and the Radix Babylon account address of a public key.
+///
+/// [`Account`] uses [`Ed25519Scheme`], the curve of all keys created by the
+/// Radix Wallet, [`Secp256k1Scheme`] is the curve of the legacy Olympia keys.
+pub trait CurveScheme {
+    /// The private key type of this curve.
+    type PrivateKey;
+
+    /// The public key type of this curve.
+    type PublicKey;
+
+    /// Derives the key pair at `path` from the BIP-39 `seed`.
+    ///
+    /// Returns `Err` if the curve does not support `path`.
+    fn derive<const N: usize>(
+        seed: &Seed,
+        path: &BIP32Path<N>,
+    ) -> Result<(Self::PrivateKey, Self::PublicKey)>;
+
+    /// The bech32m encoded Radix Babylon (virtual) account address of
+    /// `public_key` on `network_id`.
+    fn address(public_key: &Self::PublicKey, network_id: &NetworkID) -> Result<String>;
+}
+
+/// SLIP-10 Ed25519 derivation, only supporting hardened path components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ed25519Scheme;
+
+impl CurveScheme for Ed25519Scheme {
+    type PrivateKey = SecretKey;
+    type PublicKey = PublicKey;
+
+    fn derive<const N: usize>(seed: &Seed, path: &BIP32Path<N>) -> Result<(SecretKey, PublicKey)> {
+        derive_ed25519_key_pair(seed.as_bytes(), &path.inner())
+    }
+
+    fn address(public_key: &PublicKey, network_id: &NetworkID) -> Result<String> {
+        derive_address(public_key, network_id)
+    }
+}
+
+/// BIP-32 secp256k1 derivation, as used by the Olympia wallets, supporting non
+/// hardened path components. The keys are the 32 bytes private key and the 33
+/// bytes compressed public key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Secp256k1Scheme;
+
+impl CurveScheme for Secp256k1Scheme {
+    type PrivateKey = [u8; 32];
+    type PublicKey = [u8; 33];
+
+    fn derive<const N: usize>(seed: &Seed, path: &BIP32Path<N>) -> Result<([u8; 32], [u8; 33])> {
+        Ok(derive_secp256k1_key_pair(
+            seed.as_bytes(),
+            &path.derivation_path(),
+        ))
+    }
+
+    /// The Babylon address of the key, which is how Olympia accounts appear
+    /// once imported into the Radix Wallet, not the legacy Olympia address,
+    /// see [`OlympiaAccount`].
+    fn address(public_key: &[u8; 33], network_id: &NetworkID) -> Result<String> {
+        let address_data = ComponentAddress::preallocated_account_from_public_key(
+            &Secp256k1PublicKey(*public_key),
+        );
+        AddressBech32Encoder::new(&network_id.network_definition())
+            .encode(&address_data.to_vec()[..])
+            .map_err(|e| Error::AddressEncodingFailed(format!("{e:?}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn derive_with<S: CurveScheme, const N: usize>(
+        seed: &Seed,
+        path: &BIP32Path<N>,
+        network_id: &NetworkID,
+    ) -> Result<(S::PrivateKey, String)> {
+        let (private_key, public_key) = S::derive(seed, path)?;
+        S::address(&public_key, network_id).map(|address| (private_key, address))
+    }
+
+    #[test]
+    fn ed25519_scheme_eq_account() {
+        let seed = Seed::from_mnemonic(&Mnemonic24Words::test_0(), "");
+        let path = AccountPath::new(&NetworkID::Mainnet, 0);
+        let (private_key, address) =
+            derive_with::<Ed25519Scheme, 6>(&seed, &path.0, &NetworkID::Mainnet).unwrap();
+        let account = Account::derive_from_seed(&seed, &path).unwrap();
+        assert_eq!(private_key.to_hex(), account.private_key.to_hex());
+        assert_eq!(address, account.address);
+    }
+
+    #[test]
+    fn ed25519_scheme_non_hardened_is_err() {
+        let seed = Seed::from_mnemonic(&Mnemonic24Words::test_0(), "");
+        let path = OlympiaAccountPath::new(0);
+        assert!(matches!(
+            Ed25519Scheme::derive(&seed, &path.0),
+            Err(Error::KeyDerivationFailed(_))
+        ));
+    }
+
+    #[test]
+    fn secp256k1_scheme_eq_olympia_account() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_0());
+        let seed = Seed::from_mnemonic(&mnemonic, "");
+        let path = OlympiaAccountPath::new(0);
+        let (private_key, address) =
+            derive_with::<Secp256k1Scheme, 5>(&seed, &path.0, &NetworkID::Stokenet).unwrap();
+        let olympia = OlympiaAccount::derive(&mnemonic, "", &path);
+        assert_eq!(private_key, olympia.private_key);
+        assert!(address.starts_with("account_tdx_2_1"));
+        assert_ne!(address, olympia.address);
+    }
+}
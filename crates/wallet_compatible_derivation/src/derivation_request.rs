@@ -0,0 +1,241 @@
+use crate::prelude::*;
+
+use std::ops::Range;
+
+/// A validated request to derive `count` accounts on a network, starting at
+/// index `start`, using a mnemonic and BIP-39 passphrase, the library
+/// counterpart of the arguments of the CLI. Created with
+/// [`DerivationRequest::builder`], derive the accounts with `execute`.
+///
+/// The mnemonic and passphrase are zeroized on drop.
+///
+/// ```
+/// extern crate wallet_compatible_derivation;
+/// use wallet_compatible_derivation::prelude::*;
+///
+/// let mnemonic: Mnemonic24Words = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate".parse().unwrap();
+///
+/// let accounts = DerivationRequest::builder()
+///     .mnemonic(mnemonic)
+///     .network(NetworkID::Stokenet)
+///     .start(1)
+///     .count(2)
+///     .build()
+///     .unwrap()
+///     .execute()
+///     .unwrap();
+///
+/// assert_eq!(accounts[0].address, "account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivationRequest {
+    mnemonic: Mnemonic,
+    passphrase: Passphrase,
+    network_id: NetworkID,
+    start: EntityIndex,
+    count: u32,
+}
+
+impl DerivationRequest {
+    /// A builder of a `DerivationRequest`, only the mnemonic is required, see
+    /// [`DerivationRequestBuilder`] for the defaults of all other parameters.
+    pub fn builder() -> DerivationRequestBuilder {
+        DerivationRequestBuilder::default()
+    }
+
+    /// The network the accounts are derived on.
+    pub fn network_id(&self) -> NetworkID {
+        self.network_id.clone()
+    }
+
+    /// The indices of the accounts to derive, `start..start + count`, which is
+    /// never empty and never goes past the largest valid account index.
+    pub fn range(&self) -> Range<EntityIndex> {
+        self.start..self.start + self.count
+    }
+
+    /// Derives the requested accounts, ordered by ascending index, computing the
+    /// BIP-39 seed only once, see [`Account::derive_range`].
+    pub fn execute(&self) -> Result<Vec<Account>> {
+        Account::derive_range(
+            &self.mnemonic,
+            &self.passphrase,
+            &self.network_id,
+            self.range(),
+        )
+    }
+}
+
+/// Builds a [`DerivationRequest`], validating it in `build`.
+///
+/// The passphrase defaults to the empty passphrase, the network to
+/// `NetworkID::Mainnet`, `start` to `0` and `count` to `1`.
+#[derive(Clone, Debug, Default)]
+pub struct DerivationRequestBuilder {
+    mnemonic: Option<Mnemonic>,
+    passphrase: Passphrase,
+    network_id: Option<NetworkID>,
+    start: EntityIndex,
+    count: Option<u32>,
+}
+
+impl DerivationRequestBuilder {
+    /// The mnemonic to derive the accounts with, required.
+    pub fn mnemonic(mut self, mnemonic: impl Into<Mnemonic>) -> Self {
+        self.mnemonic = Some(mnemonic.into());
+        self
+    }
+
+    /// The BIP-39 passphrase to derive the accounts with, can be the empty
+    /// string, which is what the Radix Wallet uses.
+    pub fn passphrase(mut self, passphrase: impl Into<Passphrase>) -> Self {
+        self.passphrase = passphrase.into();
+        self
+    }
+
+    /// The network to derive the accounts on.
+    pub fn network(mut self, network_id: NetworkID) -> Self {
+        self.network_id = Some(network_id);
+        self
+    }
+
+    /// The index of the first account to derive.
+    pub fn start(mut self, start: EntityIndex) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// The number of accounts to derive, at least `1`.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Validates the request, returns `Err` if no mnemonic was set, if `count`
+    /// is `0`, or if the account at the last index, `start + count - 1`, cannot
+    /// be derived since the index is not less than `2^31`.
+    pub fn build(self) -> Result<DerivationRequest> {
+        let mnemonic = self
+            .mnemonic
+            .ok_or(Error::DerivationRequestMissingMnemonic)?;
+        let count = self.count.unwrap_or(1);
+        let is_valid_range = count > 0
+            && self
+                .start
+                .checked_add(count)
+                .is_some_and(|end| end <= harden(0));
+        if !is_valid_range {
+            return Err(Error::InvalidDerivationRange {
+                start: self.start,
+                count,
+            });
+        }
+        Ok(DerivationRequest {
+            mnemonic,
+            passphrase: self.passphrase,
+            network_id: self.network_id.unwrap_or(NetworkID::Mainnet),
+            start: self.start,
+            count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn valid_build() {
+        let request = DerivationRequest::builder()
+            .mnemonic(Mnemonic24Words::test_0())
+            .passphrase("")
+            .network(NetworkID::Stokenet)
+            .start(0)
+            .count(2)
+            .build()
+            .unwrap();
+        assert_eq!(request.range(), 0..2);
+        assert_eq!(request.network_id(), NetworkID::Stokenet);
+        let accounts = request.execute().unwrap();
+        assert_eq!(
+            accounts
+                .iter()
+                .map(|a| a.address.clone())
+                .collect::<Vec<_>>(),
+            vec![
+                "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8",
+                "account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+            ]
+        );
+    }
+
+    #[test]
+    fn defaults() {
+        let request = DerivationRequest::builder()
+            .mnemonic(Mnemonic24Words::test_0())
+            .build()
+            .unwrap();
+        assert_eq!(request.range(), 0..1);
+        let accounts = request.execute().unwrap();
+        assert_eq!(
+            accounts[0].address,
+            "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+        );
+    }
+
+    #[test]
+    fn last_valid_index() {
+        let request = DerivationRequest::builder()
+            .mnemonic(Mnemonic24Words::test_0())
+            .start(harden(0) - 1)
+            .build()
+            .unwrap();
+        assert_eq!(request.execute().unwrap()[0].index, harden(0) - 1);
+    }
+
+    #[test]
+    fn rejects_out_of_range_start() {
+        let build = |start, count| {
+            DerivationRequest::builder()
+                .mnemonic(Mnemonic24Words::test_0())
+                .start(start)
+                .count(count)
+                .build()
+        };
+        assert_eq!(
+            build(harden(0), 1),
+            Err(Error::InvalidDerivationRange {
+                start: harden(0),
+                count: 1
+            })
+        );
+        assert_eq!(
+            build(harden(0) - 1, 2),
+            Err(Error::InvalidDerivationRange {
+                start: harden(0) - 1,
+                count: 2
+            })
+        );
+        assert_eq!(
+            build(u32::MAX, 1),
+            Err(Error::InvalidDerivationRange {
+                start: u32::MAX,
+                count: 1
+            })
+        );
+        assert_eq!(
+            build(0, 0),
+            Err(Error::InvalidDerivationRange { start: 0, count: 0 })
+        );
+    }
+
+    #[test]
+    fn rejects_missing_mnemonic() {
+        assert_eq!(
+            DerivationRequest::builder()
+                .network(NetworkID::Stokenet)
+                .build(),
+            Err(Error::DerivationRequestMissingMnemonic)
+        );
+    }
+}
@@ -1,15 +1,65 @@
 use crate::prelude::*;
 
 use ed25519_dalek::PublicKey;
-use radix_common::prelude::*;
+use radix_common::prelude::{AddressBech32Encoder, ComponentAddress};
 
 /// Creates a bech32m encoded Radix canonical address from an Ed25519 PublicKey and a
 /// Radix `NetworkID`.
-pub(crate) fn derive_address(public_key: &PublicKey, network_id: &NetworkID) -> String {
-    let public_key = Ed25519PublicKey::try_from(public_key.to_bytes().as_slice()).expect("Should always be able to create a Radix Engine Ed25519PublicKey from Dalek Ed25519 public key");
-    let address_data = ComponentAddress::preallocated_account_from_public_key(&public_key);
+pub(crate) fn derive_address(public_key: &PublicKey, network_id: &NetworkID) -> Result<String> {
+    let address_data = derive_component_address(public_key);
     let address_encoder = AddressBech32Encoder::new(&network_id.network_definition());
     address_encoder
         .encode(&address_data.to_vec()[..])
-        .expect("bech32 account address")
+        .map_err(|e| Error::AddressEncodingFailed(format!("{e:?}")))
+}
+
+/// Creates a bech32m encoded Radix canonical identity address, used by Personas,
+/// from an Ed25519 PublicKey and a Radix `NetworkID`.
+pub(crate) fn derive_identity_address(
+    public_key: &PublicKey,
+    network_id: &NetworkID,
+) -> Result<String> {
+    let public_key = radix_engine_public_key(public_key);
+    let address_data = ComponentAddress::preallocated_identity_from_public_key(&public_key);
+    let address_encoder = AddressBech32Encoder::new(&network_id.network_definition());
+    address_encoder
+        .encode(&address_data.to_vec()[..])
+        .map_err(|e| Error::AddressEncodingFailed(format!("{e:?}")))
+}
+
+/// Converts a Dalek Ed25519 PublicKey into the Radix Engine representation.
+pub(crate) fn radix_engine_public_key(public_key: &PublicKey) -> Ed25519PublicKey {
+    Ed25519PublicKey(public_key.to_bytes())
+}
+
+/// Creates the (virtual) account `ComponentAddress` of an Ed25519 PublicKey, which
+/// is network agnostic, it is only the bech32m encoding which is network specific.
+pub(crate) fn derive_component_address(public_key: &PublicKey) -> ComponentAddress {
+    let public_key = radix_engine_public_key(public_key);
+    ComponentAddress::preallocated_account_from_public_key(&public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Golden test guarding against changes of the address encoding in the
+    /// Scrypto crates (`radix-common`, see `Cargo.toml` for the version), which
+    /// would silently change EVERY derived address. Isolated from key derivation
+    /// by using the public key of the README example directly.
+    ///
+    /// If this test fails after bumping Scrypto, the bump MUST NOT be merged
+    /// as is - the address below is the one the Radix Wallet produces.
+    #[test]
+    fn golden_address_encoding_of_readme_example() {
+        let public_key = PublicKey::from_bytes(
+            &hex::decode("d24cc6af91c3f103d7f46e5691ce2af9fea7d90cfb89a89d5bba4b513b34be3b")
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            derive_address(&public_key, &NetworkID::Mainnet).unwrap(),
+            "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8"
+        );
+    }
 }
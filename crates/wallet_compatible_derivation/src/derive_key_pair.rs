@@ -1,19 +1,74 @@
+use crate::prelude::*;
+
 use ed25519_dalek::{PublicKey, SecretKey};
 
 /// Derives an Ed255519 key pair on [`Curve25519`][curve],
 /// using the hierarchal deterministic BIP-32 derivation `path`,
 /// and the `seed` of a hierarchal deterministic tree.
 ///
+/// Returns `Err` if `path` is invalid, e.g. has a non hardened component, which
+/// SLIP-10 Ed25519 derivation does not support.
+///
 /// [curve]: https://en.wikipedia.org/wiki/Curve25519
 pub(crate) fn derive_ed25519_key_pair(
     seed: &[u8],
     path: &slip10::path::BIP32Path,
-) -> (SecretKey, PublicKey) {
-    let key = slip10::derive_key_from_path(&seed, slip10::Curve::Ed25519, path).expect("Should never fail to derive Ed25519 Private key from seed for a valid BIP32Path - internal error, something wrong with SLIP10 Crate most likely");
-    // Ed25519PrivateKey::from_bytes(&key.key)
-    //     .expect("Should always be able to create Ed25519PrivateKey from derived key.")
-    let private_key = SecretKey::from_bytes(&key.key)
-        .expect("Should always be able to create Ed25519PrivateKey from derived key.");
+) -> Result<(SecretKey, PublicKey)> {
+    let key = slip10::derive_key_from_path(seed, slip10::Curve::Ed25519, path)
+        .map_err(|e| Error::KeyDerivationFailed(e.to_string()))?;
+    let private_key =
+        SecretKey::from_bytes(&key.key).map_err(|e| Error::KeyDerivationFailed(e.to_string()))?;
     let public_key: PublicKey = (&private_key).into();
-    (private_key, public_key)
+    Ok((private_key, public_key))
+}
+
+/// Derives a secp256k1 key pair, using the hierarchal deterministic BIP-32
+/// derivation `path` - which unlike SLIP-10 Ed25519 derivation supports non
+/// hardened path components - and the `seed` of a hierarchal deterministic tree.
+///
+/// Returns the 32 bytes private key and the 33 bytes compressed public key.
+pub(crate) fn derive_secp256k1_key_pair(
+    seed: &[u8],
+    path: &bip32::DerivationPath,
+) -> ([u8; 32], [u8; 33]) {
+    let extended_private_key = bip32::XPrv::derive_from_path(seed, path).expect("Should never fail to derive secp256k1 Private key from seed for a valid BIP32Path - internal error, something wrong with BIP32 Crate most likely");
+    let public_key = extended_private_key.public_key().to_bytes();
+    (extended_private_key.to_bytes(), public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// Test vector 1 of [BIP-32][bip32], the derivation used by the Olympia
+    /// wallets, anchoring the Olympia account vectors to an external source.
+    ///
+    /// [bip32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki#test-vector-1
+    #[test]
+    fn secp256k1_bip32_test_vector_1() {
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let derive = |path: &str| {
+            let (private_key, public_key) =
+                derive_secp256k1_key_pair(&seed, &path.parse().unwrap());
+            (hex::encode(private_key), hex::encode(public_key))
+        };
+        assert_eq!(
+            derive("m"),
+            (
+                "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35".to_owned(),
+                "0339a36013301597daef41fbe593a02cc513d0b55527ec2df1050e2e8ff49c85c2".to_owned()
+            )
+        );
+        assert_eq!(
+            derive("m/0'"),
+            (
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea".to_owned(),
+                "035a784662a4a20a65bf6aab9ae98a6c068a81c52e4b032c0fb5400c706cfccc56".to_owned()
+            )
+        );
+        assert_eq!(
+            derive("m/0'/1").1,
+            "03501e454bf00751f24b1b489aa925215d66af2234e3891c3b21a52bedb3cd711c"
+        );
+    }
 }
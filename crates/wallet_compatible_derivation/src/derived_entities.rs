@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+/// The accounts and personas derived by [`derive_entities`], from the same
+/// mnemonic and passphrase.
+#[derive(ZeroizeOnDrop, Zeroize, Debug)]
+pub struct DerivedEntities {
+    /// The derived accounts, in the order of the account indices.
+    pub accounts: Vec<Account>,
+
+    /// The derived personas, in the order of the identity indices.
+    pub personas: Vec<Persona>,
+}
+
+/// Derives the accounts at every index in `account_indices` and the personas
+/// (identities) at every index in `identity_indices`, all on `network_id`, using
+/// the `mnemonic` and BIP-39 `passphrase` (can be the empty string), computing
+/// the BIP-39 seed only once.
+///
+/// The accounts and personas are ordered exactly like their indices are
+/// iterated, e.g. ascending for ranges, duplicate indices are derived again.
+///
+/// Returns `Err` as soon as deriving any account or persona fails.
+pub fn derive_entities(
+    mnemonic: &Mnemonic,
+    passphrase: impl Into<Passphrase>,
+    network_id: &NetworkID,
+    account_indices: impl IntoIterator<Item = EntityIndex>,
+    identity_indices: impl IntoIterator<Item = EntityIndex>,
+) -> Result<DerivedEntities> {
+    let seed = Seed::from_mnemonic(mnemonic, passphrase);
+    let factor_source_id = FactorSourceID::from_seed(&seed);
+    let accounts = account_indices
+        .into_iter()
+        .map(|index| {
+            Account::derive_from_seed_with_id(
+                &seed,
+                &factor_source_id,
+                &AccountPath::new(network_id, index),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let personas = identity_indices
+        .into_iter()
+        .map(|index| {
+            Persona::derive_from_seed_with_id(
+                &seed,
+                &factor_source_id,
+                &IdentityPath::new(network_id, index),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(DerivedEntities { accounts, personas })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn one_account_and_one_identity() {
+        let entities = derive_entities(
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Mainnet,
+            [0],
+            [1],
+        )
+        .unwrap();
+        assert_eq!(entities.accounts.len(), 1);
+        assert_eq!(entities.personas.len(), 1);
+        assert_eq!(
+            entities.accounts[0].address,
+            "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+        );
+        assert_eq!(
+            entities.personas[0].address,
+            "identity_rdx12fvknhjau20pxmampj3vfnem3eddg0kff40nh4enpkqr2hhlzf8dfd"
+        );
+        assert_eq!(
+            entities.accounts[0].factor_source_id,
+            entities.personas[0].factor_source_id
+        );
+    }
+
+    #[test]
+    fn ordered_like_indices() {
+        let entities = derive_entities(
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Stokenet,
+            [2, 0, 1],
+            0..2,
+        )
+        .unwrap();
+        assert_eq!(
+            entities
+                .accounts
+                .iter()
+                .map(|a| a.index)
+                .collect::<Vec<_>>(),
+            [2, 0, 1]
+        );
+        assert_eq!(
+            entities
+                .personas
+                .iter()
+                .map(|p| p.index)
+                .collect::<Vec<_>>(),
+            [0, 1]
+        );
+        let debug = format!("{entities:?}");
+        assert!(!debug.contains(&entities.personas[0].private_key.to_hex()));
+    }
+}
@@ -0,0 +1,78 @@
+use strum_macros::Display;
+
+use crate::prelude::*;
+
+/// The kind of Radix entity, or rather the purpose, keys are derived for,
+/// used as the (unhardened) value of the `entity_kind` path component.
+///
+/// The values are the sum of the ASCII values of the uppercased name of the
+/// kind, see `test_asciisum`.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Display, enum_iterator::Sequence,
+)]
+#[repr(u32)]
+pub enum EntityKind {
+    /// Keys for Pre-allocated [accounts][account].
+    ///
+    /// [account]: https://docs.radixdlt.com/docs/account
+    Account = 525,
+
+    /// Keys for Pre-allocated [identities][identity], used by Personas.
+    ///
+    /// [identity]: https://docs.radixdlt.com/docs/identity
+    Identity = 618,
+
+    /// Not an entity, but the path used to derive the key from which the
+    /// `FactorSourceID` of a mnemonic is formed.
+    GetId = 365,
+}
+
+impl EntityKind {
+    /// Returns a collection of all entity kinds.
+    pub fn all() -> Vec<EntityKind> {
+        enum_iterator::all::<EntityKind>().collect::<Vec<_>>()
+    }
+
+    /// The unhardened value of this entity kind, e.g. `525` for `Account`.
+    pub const fn value(&self) -> HDPathComponentValue {
+        *self as HDPathComponentValue
+    }
+
+    /// Returns `<self.value()>H`, that is, the value of the entity kind
+    /// but hardened, as per SLIP10.
+    pub const fn hardened_hd_component_value(&self) -> HDPathComponentValue {
+        harden(self.value())
+    }
+
+    /// Tries to create an `EntityKind` from its unhardened `value`, e.g. `525`.
+    pub fn from_value(value: HDPathComponentValue) -> Result<Self> {
+        Self::all()
+            .into_iter()
+            .find(|k| k.value() == value)
+            .ok_or(Error::UnknownEntityKind(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn value_roundtrip() {
+        for kind in EntityKind::all() {
+            assert_eq!(EntityKind::from_value(kind.value()), Ok(kind));
+        }
+        assert_eq!(EntityKind::Account.value(), 525);
+        assert_eq!(
+            EntityKind::Identity.hardened_hd_component_value(),
+            harden(618)
+        );
+        assert_eq!(EntityKind::from_value(1), Err(Error::UnknownEntityKind(1)));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(EntityKind::Account.to_string(), "Account");
+        assert_eq!(EntityKind::GetId.to_string(), "GetId");
+    }
+}
@@ -1,6 +1,6 @@
 use thiserror::Error as ThisError;
 
-use crate::HDPathComponentValue;
+use crate::{EntityKind, HDPathComponentValue};
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -9,6 +9,17 @@ pub enum Error {
     #[error("Invalid BIP-39 mnemonic")]
     InvalidMnemonic,
 
+    #[error(
+        "Unknown word '{word}' at position {position} of the mnemonic, it is not a BIP-39 word"
+    )]
+    UnknownMnemonicWord { word: String, position: usize },
+
+    #[error("Invalid BIP-39 mnemonic checksum, all words are BIP-39 words, but some word is wrong or the words are in the wrong order")]
+    InvalidMnemonicChecksum,
+
+    #[error("Empty mnemonic, please input a 12, 15, 18, 21 or 24 words BIP-39 mnemonic")]
+    EmptyMnemonic,
+
     #[error(
         "Mnemonic has too few words, only {expected} words mnemonics are supported, found: {found}"
     )]
@@ -20,6 +31,28 @@ pub enum Error {
     #[error("Unsupported or unknown Network ID: '{0}'")]
     UnsupportedOrUnknownNetworkIDFromStr(String),
 
+    #[error("Invalid address or address of unknown network: '{0}'")]
+    InvalidAddressOrUnknownNetwork(String),
+
+    #[error("Unknown entity kind: '{0}'")]
+    UnknownEntityKind(HDPathComponentValue),
+
+    #[error("Unknown key kind: '{0}'")]
+    UnknownKeyKind(HDPathComponentValue),
+
+    #[error("Account index too large, must be less than 2^31, but group: {group} * group_size: {group_size} + offset: {offset} is not.")]
+    AccountIndexInGroupTooLarge {
+        group: u32,
+        offset: u32,
+        group_size: u32,
+    },
+
+    #[error("Offset: {offset} must be less than group_size: {group_size}.")]
+    AccountGroupOffsetOutOfBounds { offset: u32, group_size: u32 },
+
+    #[error("Invalid Olympia account path (but valid BIP-32): '{0}'")]
+    InvalidOlympiaAccountPath(String),
+
     #[error("Invalid BIP-32 HD path: '{0}'")]
     InvalidBIP32Path(String),
 
@@ -42,9 +75,51 @@ pub enum Error {
         found: HDPathComponentValue,
     },
 
+    #[error("Invalid Radix Account path, it is a path for another kind of entity, expected: {expected}, found {found}.")]
+    PathIsForDifferentEntityKind {
+        expected: EntityKind,
+        found: EntityKind,
+    },
+
     #[error("Invalid Radix Account path, invalid value at index: {index} found {found}.")]
     InvalidAccountPathInvalidValue {
         index: usize,
         found: HDPathComponentValue,
     },
+
+    #[error("Invalid securified account index, must be in the securified half of the hardened keyspace, 2^30..2^31, found: {0}")]
+    InvalidSecurifiedIndex(HDPathComponentValue),
+
+    #[error("Invalid FactorSourceID, must be the hex of 32 bytes, found: '{0}'")]
+    InvalidFactorSourceID(String),
+
+    #[error("Invalid seed, must be 64 bytes encoded as 128 hex characters")]
+    InvalidSeedHex,
+
+    #[error("Invalid entropy, must be 32 bytes encoded as 64 hex characters")]
+    InvalidEntropyHex,
+
+    #[error("Missing mnemonic, a DerivationRequest requires a mnemonic")]
+    DerivationRequestMissingMnemonic,
+
+    #[error("Invalid derivation range, must derive at least one account and every index must be less than 2^31, but start: {start}, count: {count} does not.")]
+    InvalidDerivationRange { start: u32, count: u32 },
+
+    #[error("Invalid binary account record: {0}")]
+    InvalidAccountBytes(String),
+
+    #[error("Failed to derive key: {0}")]
+    KeyDerivationFailed(String),
+
+    #[error("Failed to encode address: {0}")]
+    AddressEncodingFailed(String),
+
+    #[error("Request to the Radix Gateway failed: {0}")]
+    GatewayRequestFailed(String),
+
+    #[error("Invalid keystore: {0}")]
+    InvalidKeystore(String),
+
+    #[error("Failed to decrypt keystore, wrong password or the keystore has been tampered with")]
+    KeystoreDecryptionFailed,
 }
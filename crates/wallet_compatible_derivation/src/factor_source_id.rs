@@ -1,5 +1,6 @@
 use crate::prelude::*;
-use radix_common::prelude::*;
+use radix_common::prelude::{blake2b_256_hash, IsHash};
+use std::collections::HashMap;
 
 /// A safe to use hex encoding of the hash of a public key at a special node in your BIP-39 Seed,
 /// This ID is used to identify that two accounts have been derived from the same mnemonic.
@@ -7,7 +8,9 @@ use radix_common::prelude::*;
 /// And the public key is not the public key of any account signing key, a
 /// special derivation path which is different from that of accounts have been used
 /// to derive this key pair.
-#[derive(Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq, derive_more::Display)]
+#[derive(
+    Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display,
+)]
 #[display("{}", self.to_hex())]
 pub struct FactorSourceID([u8; 32]);
 
@@ -17,16 +20,171 @@ impl ToHex for FactorSourceID {
     }
 }
 
+impl AsRef<[u8]> for FactorSourceID {
+    /// The 32 bytes of this ID, e.g. for hashing or writing it without allocating.
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for FactorSourceID {
+    type Err = crate::Error;
+
+    /// Decodes an ID from the hex of its 32 bytes, as formatted by `to_hex`.
+    ///
+    /// Returns `Err(Error::InvalidFactorSourceID)` if `s` is not the hex of
+    /// exactly 32 bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(s, &mut bytes)
+            .map_err(|_| Error::InvalidFactorSourceID(s.to_owned()))?;
+        Ok(Self(bytes))
+    }
+}
+
 impl FactorSourceID {
+    /// Creates the SAFE to use ID of the `mnemonic` and BIP-39 `passphrase` (can
+    /// be the empty string), which is the same ID as the one of every account
+    /// derived using them.
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: impl Into<Passphrase>) -> Self {
+        Self::from_seed(&Seed::from_mnemonic(mnemonic, passphrase))
+    }
+
+    /// The 32 bytes of this ID.
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
     /// Creates a SAFE to use ID from a hierarchal deterministic tree's `seed`, by
     /// deriving a special public key at a non-leaf (non account) node in the tree,
     /// and then hashing that public key, using the `blake2b_256_hash` algorithm.
-    pub(crate) fn from_seed(seed: &[u8]) -> Self {
-        let components: Vec<HDPathComponentValue> = vec![PURPOSE, COINTYPE, harden(365)];
+    ///
+    /// Useful if only the BIP-39 `seed` is known, not the mnemonic.
+    pub fn from_seed(seed: &Seed) -> Self {
+        let components: Vec<HDPathComponentValue> = vec![
+            PURPOSE,
+            COINTYPE,
+            EntityKind::GetId.hardened_hd_component_value(),
+        ];
         let path = slip10::path::BIP32Path::from(components);
-        let (private_key, public_key) = derive_ed25519_key_pair(seed, &path);
+        let (private_key, public_key) = derive_ed25519_key_pair(seed.as_bytes(), &path)
+            .expect("Should always be able to derive the key at the constant GetID path.");
         drop(private_key);
-        let hash = blake2b_256_hash(&public_key.as_bytes());
+        let hash = blake2b_256_hash(public_key.as_bytes());
         Self(hash.into_bytes())
     }
 }
+
+/// Groups the indices of `mnemonics` - tuples of mnemonic and BIP-39 passphrase - by
+/// their `FactorSourceID`, which is derived exactly once per tuple.
+///
+/// Useful to identify which of many mnemonics are in fact the same wallet, note
+/// that the same mnemonic with different passphrases are different wallets.
+///
+/// All intermediate seeds are zeroized.
+pub fn group_by_factor_source(
+    mnemonics: &[(Mnemonic24Words, String)],
+) -> HashMap<FactorSourceID, Vec<usize>> {
+    let mut groups = HashMap::<FactorSourceID, Vec<usize>>::new();
+    for (index, (mnemonic, passphrase)) in mnemonics.iter().enumerate() {
+        groups
+            .entry(FactorSourceID::from_mnemonic(mnemonic, passphrase))
+            .or_default()
+            .push(index);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn from_mnemonic() {
+        assert_eq!(
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), "").to_string(),
+            "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033"
+        );
+        assert_eq!(
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), "radix").to_string(),
+            "3c986ebf9dcd9167a97036d3b2c997433e85e6cc4e4422ad89269dac7bfea240"
+        );
+    }
+
+    #[test]
+    fn differs_by_mnemonic_and_passphrase() {
+        let ids = [
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), ""),
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_1(), ""),
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), "radix"),
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), ""),
+        ];
+        let unique = ids.iter().cloned().collect::<HashSet<_>>();
+        assert_eq!(unique.len(), 3);
+        assert_ne!(ids[0], ids[1]);
+        assert_ne!(ids[0], ids[2]);
+        assert_eq!(ids[0], ids[3]);
+    }
+
+    #[test]
+    fn as_ref() {
+        let id = FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), "");
+        assert_eq!(hex::encode(id.as_ref()), id.to_hex());
+    }
+
+    #[test]
+    fn as_bytes() {
+        let id = FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), "");
+        assert_eq!(hex::encode(id.as_bytes()), id.to_hex());
+    }
+
+    #[test]
+    fn from_str_roundtrip() {
+        for id in [
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_0(), ""),
+            FactorSourceID::from_mnemonic(&Mnemonic24Words::test_1(), "radix"),
+        ] {
+            assert_eq!(id.to_string().parse::<FactorSourceID>(), Ok(id));
+        }
+        assert_eq!(
+            "6FACB00A836864511FDF8F181382209E64E83AD462288EA1BC7868F236FB8033"
+                .parse::<FactorSourceID>()
+                .unwrap()
+                .to_string(),
+            "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033"
+        );
+    }
+
+    #[test]
+    fn from_str_invalid() {
+        for s in [
+            "",
+            "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb80",
+            "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb803300",
+            "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb803g",
+        ] {
+            assert_eq!(
+                s.parse::<FactorSourceID>(),
+                Err(Error::InvalidFactorSourceID(s.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn group_by_factor_source() {
+        let groups = super::group_by_factor_source(&[
+            (Mnemonic24Words::test_0(), "".to_owned()),
+            (Mnemonic24Words::test_1(), "".to_owned()),
+            (Mnemonic24Words::test_0(), "radix".to_owned()),
+            (Mnemonic24Words::test_0(), "".to_owned()),
+        ]);
+        assert_eq!(groups.len(), 3);
+        let group_of = |mnemonic: Mnemonic24Words, passphrase| {
+            groups[&FactorSourceID::from_mnemonic(&mnemonic, passphrase)].clone()
+        };
+        assert_eq!(group_of(Mnemonic24Words::test_0(), ""), vec![0, 3]);
+        assert_eq!(group_of(Mnemonic24Words::test_1(), ""), vec![1]);
+        assert_eq!(group_of(Mnemonic24Words::test_0(), "radix"), vec![2]);
+    }
+}
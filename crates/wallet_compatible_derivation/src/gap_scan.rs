@@ -0,0 +1,222 @@
+use crate::prelude::*;
+
+/// A source of whether accounts have been used on ledger, e.g. the Radix
+/// Gateway, see `GatewayClient` (requires the `gateway` feature), or a mock in
+/// tests.
+pub trait AccountActivity {
+    /// Whether the account with the bech32 encoded `address` has been used on
+    /// ledger, i.e. if it holds any resources or has any metadata.
+    fn is_account_used(&self, address: &str) -> Result<bool>;
+}
+
+/// One derived account of a [`ScanReport`], without any keys.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanEntry {
+    /// The index of the account.
+    pub index: EntityIndex,
+
+    /// The bech32 encoded address of the account.
+    pub address: String,
+
+    /// Whether the account has been used on ledger.
+    pub is_used: bool,
+}
+
+/// The report of a gap scan, which derives accounts from index `0` until
+/// `gap_limit` consecutive unused accounts, see [`scan_report_with`], e.g. to
+/// show a user restoring a wallet that accounts `0`, `2` and `5` are active,
+/// while `1`, `3` and `4` are empty.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanReport {
+    /// The network the accounts were derived on, and queried from.
+    pub network_id: NetworkID,
+
+    /// The number of consecutive unused accounts the scan stopped after.
+    pub gap_limit: u32,
+
+    /// Every scanned account, ordered by ascending index.
+    pub entries: Vec<ScanEntry>,
+}
+
+impl ScanReport {
+    /// The indices of all used accounts.
+    pub fn used_indices(&self) -> Vec<EntityIndex> {
+        self.indices(true)
+    }
+
+    /// The indices of all unused accounts, including the terminating gap.
+    pub fn unused_indices(&self) -> Vec<EntityIndex> {
+        self.indices(false)
+    }
+
+    fn indices(&self, is_used: bool) -> Vec<EntityIndex> {
+        self.entries
+            .iter()
+            .filter(|e| e.is_used == is_used)
+            .map(|e| e.index)
+            .collect()
+    }
+
+    /// The index of the first unused account after the last used one, where the
+    /// terminating gap starts, i.e. the next account a wallet would create.
+    /// `None` if no accounts were scanned.
+    pub fn gap_start(&self) -> Option<EntityIndex> {
+        let first_unused = self
+            .entries
+            .iter()
+            .rposition(|e| e.is_used)
+            .map_or(0, |last_used| last_used + 1);
+        self.entries.get(first_unused).map(|e| e.index)
+    }
+
+    /// The index of the last scanned account, where the terminating gap, and
+    /// thus the scan, ended. `None` if no accounts were scanned.
+    pub fn gap_end(&self) -> Option<EntityIndex> {
+        self.entries.last().map(|e| e.index)
+    }
+}
+
+/// Derives accounts sequentially on `network_id`, starting at index `0`, using
+/// the BIP-39 `seed`, asking `activity` if each account has been used, until
+/// `gap_limit` consecutive unused accounts, returning every derived account
+/// with whether it has been used.
+pub(crate) fn scan_until_gap(
+    activity: &impl AccountActivity,
+    seed: &Seed,
+    network_id: &NetworkID,
+    gap_limit: u32,
+) -> Result<Vec<(Account, bool)>> {
+    let factor_source_id = FactorSourceID::from_seed(seed);
+    let mut scanned = Vec::new();
+    let mut unused_in_a_row = 0;
+    let mut next_path = Some(AccountPath::new(network_id, 0));
+    while let Some(path) = next_path.filter(|_| unused_in_a_row < gap_limit) {
+        let account = Account::derive_from_seed_with_id(seed, &factor_source_id, &path)?;
+        let is_used = activity.is_account_used(&account.address)?;
+        if is_used {
+            unused_in_a_row = 0;
+        } else {
+            unused_in_a_row += 1;
+        }
+        next_path = path.next();
+        scanned.push((account, is_used));
+    }
+    Ok(scanned)
+}
+
+/// Scans the accounts of `mnemonic` and BIP-39 `passphrase` on `network_id`,
+/// asking `activity` if each account has been used, until `gap_limit`
+/// consecutive unused accounts, as per the account discovery of BIP-44, and
+/// returns the [`ScanReport`]. The private keys of the derived accounts are
+/// zeroized, and never part of the report.
+///
+/// Returns `Err` if `activity` fails.
+pub fn scan_report_with(
+    activity: &impl AccountActivity,
+    mnemonic: &Mnemonic,
+    passphrase: impl Into<Passphrase>,
+    network_id: &NetworkID,
+    gap_limit: u32,
+) -> Result<ScanReport> {
+    let seed = Seed::from_mnemonic(mnemonic, passphrase);
+    let entries = scan_until_gap(activity, &seed, network_id, gap_limit)?
+        .into_iter()
+        .map(|(account, is_used)| ScanEntry {
+            index: account.index,
+            address: account.address.clone(),
+            is_used,
+        })
+        .collect();
+    Ok(ScanReport {
+        network_id: network_id.clone(),
+        gap_limit,
+        entries,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::{cell::RefCell, collections::HashSet};
+
+    /// Accounts at `used` indices are used, all others unused, records every
+    /// queried address.
+    struct SparseActivity {
+        used: HashSet<String>,
+        queried: RefCell<Vec<String>>,
+    }
+
+    impl SparseActivity {
+        fn new(used: &[EntityIndex]) -> Self {
+            let mnemonic = Mnemonic24Words::test_0();
+            let used = used
+                .iter()
+                .map(|i| {
+                    Account::derive_unwrap(
+                        &mnemonic,
+                        "",
+                        &AccountPath::new(&NetworkID::Stokenet, *i),
+                    )
+                    .address
+                    .clone()
+                })
+                .collect();
+            Self {
+                used,
+                queried: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl AccountActivity for SparseActivity {
+        fn is_account_used(&self, address: &str) -> Result<bool> {
+            self.queried.borrow_mut().push(address.to_owned());
+            Ok(self.used.contains(address))
+        }
+    }
+
+    #[test]
+    fn sparse_activity() {
+        let activity = SparseActivity::new(&[0, 2, 5]);
+        let report = scan_report_with(
+            &activity,
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Stokenet,
+            3,
+        )
+        .unwrap();
+        assert_eq!(report.used_indices(), vec![0, 2, 5]);
+        assert_eq!(report.unused_indices(), vec![1, 3, 4, 6, 7, 8]);
+        assert_eq!(report.gap_start(), Some(6));
+        assert_eq!(report.gap_end(), Some(8));
+        assert_eq!(activity.queried.borrow().len(), 9);
+        assert_eq!(
+            report.entries[1].address,
+            "account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+        );
+    }
+
+    #[test]
+    fn no_activity() {
+        let report = scan_report_with(
+            &SparseActivity::new(&[]),
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Stokenet,
+            2,
+        )
+        .unwrap();
+        assert!(report.used_indices().is_empty());
+        assert_eq!(report.gap_start(), Some(0));
+        assert_eq!(report.gap_end(), Some(1));
+
+        let empty = ScanReport {
+            network_id: NetworkID::Stokenet,
+            gap_limit: 0,
+            entries: Vec::new(),
+        };
+        assert_eq!(empty.gap_start(), None);
+        assert_eq!(empty.gap_end(), None);
+    }
+}
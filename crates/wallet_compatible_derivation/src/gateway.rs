@@ -0,0 +1,251 @@
+use crate::prelude::*;
+
+use reqwest::blocking::Client;
+use serde_json::{json, Value};
+
+impl NetworkID {
+    /// The URL of the public [Radix Gateway][gateway] of this network.
+    ///
+    /// [gateway]: https://docs.radixdlt.com/docs/network-apis
+    pub fn gateway_url(&self) -> &'static str {
+        match self {
+            NetworkID::Mainnet => "https://mainnet.radixdlt.com",
+            NetworkID::Stokenet => "https://stokenet.radixdlt.com",
+        }
+    }
+}
+
+impl Account {
+    /// Derives accounts sequentially, starting at index `0`, querying the public
+    /// Radix Gateway of `network_id` if each account has been used, stopping
+    /// after `gap_limit` consecutive unused accounts, as per the account
+    /// discovery of [BIP-44][bip44], which uses a `gap_limit` of `20`.
+    ///
+    /// Returns all accounts up to, and including, the first unused account after
+    /// the last used one, i.e. the last account returned is the next account a
+    /// wallet would create. Returns `Err` if any request to the Gateway fails.
+    ///
+    /// [bip44]: https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki#account-discovery
+    pub fn derive_until_unused(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        gap_limit: u32,
+    ) -> Result<Vec<Self>> {
+        Self::derive_until_unused_with_gateway(
+            network_id.gateway_url(),
+            mnemonic,
+            passphrase,
+            network_id,
+            gap_limit,
+        )
+    }
+
+    /// Like [`Account::derive_until_unused`], but queries the Radix Gateway at
+    /// `gateway_url`, e.g. a self hosted one, instead of the public one.
+    pub fn derive_until_unused_with_gateway(
+        gateway_url: &str,
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        gap_limit: u32,
+    ) -> Result<Vec<Self>> {
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        let mut scanned = scan_until_gap(
+            &GatewayClient::new(gateway_url),
+            &seed,
+            network_id,
+            gap_limit,
+        )?;
+        let first_unused = scanned
+            .iter()
+            .rposition(|(_, is_used)| *is_used)
+            .map_or(0, |last_used| last_used + 1);
+        scanned.truncate(first_unused + 1);
+        Ok(scanned.into_iter().map(|(account, _)| account).collect())
+    }
+}
+
+/// Scans the accounts of `mnemonic` and BIP-39 `passphrase` on `network_id`,
+/// querying the public Radix Gateway of `network_id`, see [`scan_report_with`],
+/// which accepts any [`AccountActivity`], e.g. a `GatewayClient` of a self
+/// hosted Gateway.
+pub fn scan_report(
+    mnemonic: &Mnemonic,
+    passphrase: impl Into<Passphrase>,
+    network_id: &NetworkID,
+    gap_limit: u32,
+) -> Result<ScanReport> {
+    scan_report_with(
+        &GatewayClient::new(network_id.gateway_url()),
+        mnemonic,
+        passphrase,
+        network_id,
+        gap_limit,
+    )
+}
+
+/// A client of the Radix Gateway at a URL, which queries if accounts have been
+/// used, see [`AccountActivity`].
+pub struct GatewayClient {
+    url: String,
+    client: Client,
+}
+
+impl GatewayClient {
+    /// A client of the Radix Gateway at `url`, e.g. `NetworkID::gateway_url`.
+    pub fn new(url: impl AsRef<str>) -> Self {
+        Self {
+            url: url.as_ref().trim_end_matches('/').to_owned(),
+            client: Client::new(),
+        }
+    }
+}
+
+impl AccountActivity for GatewayClient {
+    /// Queries the state of the account with `address`, see [`is_used_entity`].
+    fn is_account_used(&self, address: &str) -> Result<bool> {
+        let url = format!("{}/state/entity/details", self.url);
+        let response = self
+            .client
+            .post(url)
+            .json(&json!({ "addresses": [address] }))
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.json::<Value>())
+            .map_err(|e| Error::GatewayRequestFailed(e.to_string()))?;
+        response["items"].get(0).map(is_used_entity).ok_or_else(|| {
+            Error::GatewayRequestFailed(format!("No state of entity '{address}' in response."))
+        })
+    }
+}
+
+/// A (virtual) account which has never been used has no metadata, e.g. no
+/// `owner_keys`, and no resources on ledger.
+fn is_used_entity(item: &Value) -> bool {
+    ["metadata", "fungible_resources", "non_fungible_resources"]
+        .iter()
+        .any(|key| item[key]["total_count"].as_u64().unwrap_or(0) > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Mock, Server, ServerGuard};
+
+    fn entity_details(server: &mut ServerGuard, address: &str, is_used: bool) -> Mock {
+        let total_count = if is_used { 1 } else { 0 };
+        server
+            .mock("POST", "/state/entity/details")
+            .match_body(Matcher::PartialJson(json!({ "addresses": [address] })))
+            .with_body(
+                json!({
+                    "items": [{
+                        "address": address,
+                        "metadata": { "total_count": total_count, "items": [] },
+                        "fungible_resources": { "total_count": 0, "items": [] },
+                    }]
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create()
+    }
+
+    #[test]
+    fn derive_until_unused_stops_after_gap_limit() {
+        let mut server = Server::new();
+        let mnemonic = Mnemonic24Words::test_0();
+        let addresses = (0..6)
+            .map(|i| {
+                Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Stokenet, i))
+                    .address
+                    .clone()
+            })
+            .collect::<Vec<_>>();
+        // Used, unused, used, then `gap_limit` unused.
+        let mocks = addresses
+            .iter()
+            .enumerate()
+            .map(|(i, address)| entity_details(&mut server, address, i == 0 || i == 2))
+            .collect::<Vec<_>>();
+
+        let accounts = Account::derive_until_unused_with_gateway(
+            &server.url(),
+            &mnemonic,
+            "",
+            &NetworkID::Stokenet,
+            3,
+        )
+        .unwrap();
+        assert_eq!(
+            accounts.iter().map(|a| a.index).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(accounts[3].address, addresses[3]);
+        mocks.iter().for_each(Mock::assert);
+    }
+
+    #[test]
+    fn scan_report_from_gateway() {
+        let mut server = Server::new();
+        let mnemonic = Mnemonic24Words::test_0();
+        let mocks = (0..4)
+            .map(|i| {
+                let account = Account::derive_unwrap(
+                    &mnemonic,
+                    "",
+                    &AccountPath::new(&NetworkID::Stokenet, i),
+                );
+                entity_details(&mut server, &account.address, i == 1)
+            })
+            .collect::<Vec<_>>();
+        let report = scan_report_with(
+            &GatewayClient::new(server.url()),
+            &mnemonic,
+            "",
+            &NetworkID::Stokenet,
+            2,
+        )
+        .unwrap();
+        assert_eq!(report.used_indices(), vec![1]);
+        assert_eq!(report.unused_indices(), vec![0, 2, 3]);
+        assert_eq!(report.gap_start(), Some(2));
+        mocks.iter().for_each(Mock::assert);
+    }
+
+    #[test]
+    fn derive_until_unused_network_error() {
+        let mut server = Server::new();
+        let mock = server
+            .mock("POST", "/state/entity/details")
+            .with_status(503)
+            .create();
+        let result = Account::derive_until_unused_with_gateway(
+            &server.url(),
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Mainnet,
+            20,
+        );
+        assert!(matches!(result, Err(Error::GatewayRequestFailed(_))));
+        mock.assert();
+    }
+
+    #[test]
+    fn derive_until_unused_unexpected_response() {
+        let mut server = Server::new();
+        server
+            .mock("POST", "/state/entity/details")
+            .with_body(r#"{ "items": [] }"#)
+            .create();
+        let result = Account::derive_until_unused_with_gateway(
+            &server.url(),
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Mainnet,
+            20,
+        );
+        assert!(matches!(result, Err(Error::GatewayRequestFailed(_))));
+    }
+}
@@ -0,0 +1,93 @@
+use crate::prelude::*;
+
+/// A Radix Babylon [BIP-32][bip32] path used to derive identities, used by
+/// Personas, for example `m/44'/1022'/1'/618'/1460'/2'`.
+///
+/// Identical to [`AccountPath`] except for the `entity_kind` which is fixed to
+/// `618` (see [`EntityKind::Identity`]):
+///
+/// ```text
+/// m / 44' / 1022' / NETWORK_ID' / 618' / 1460' / IDENTITY_INDEX'
+/// ```
+///
+/// When parsing, the `AuthenticationSigning` key kind (`1678`) is accepted too.
+///
+/// [bip32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+#[derive(
+    Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, derive_more::Display,
+)]
+pub struct IdentityPath(pub(crate) BIP32Path<{ AccountPath::DEPTH }>);
+
+impl IdentityPath {
+    /// Read the `network_id` of this IdentityPath.
+    pub fn network_id(&self) -> NetworkID {
+        NetworkID::try_from(unhardened(self.0.clone().components()[AccountPath::IDX_NETWORK_ID])).expect("Should not have been possible to instantiate an Identity Path with an invalid Network ID.")
+    }
+
+    /// Read the identity `index` of this IdentityPath.
+    pub fn identity_index(&self) -> HDPathComponentValue {
+        unhardened(self.0.clone().components()[AccountPath::IDX_ACCOUNT_INDEX])
+    }
+
+    /// Crates a new `IdentityPath` given the tuple (network, index).
+    pub fn new(network_id: &NetworkID, index: EntityIndex) -> Self {
+        let bip32_path = BIP32Path::<{ AccountPath::DEPTH }>([
+            PURPOSE,
+            COINTYPE,
+            network_id.hardened_hd_component_value(),
+            EntityKind::Identity.hardened_hd_component_value(),
+            KeyKind::TransactionSigning.hardened_hd_component_value(),
+            harden(index),
+        ]);
+
+        bip32_path
+            .try_into()
+            .expect("Should have constructed a valid IdentityPath from network_id and index.")
+    }
+}
+
+impl TryFrom<BIP32Path<{ AccountPath::DEPTH }>> for IdentityPath {
+    type Error = crate::Error;
+
+    /// Tries to create a new `IdentityPath` from a `BIP32Path`, by validating it,
+    /// returning `Err` if it is invalid.
+    fn try_from(value: BIP32Path<{ AccountPath::DEPTH }>) -> Result<Self, Self::Error> {
+        validate_entity_path(&value, EntityKind::Identity)?;
+        Ok(Self(value))
+    }
+}
+
+impl FromStr for IdentityPath {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<BIP32Path<{ AccountPath::DEPTH }>>()
+            .and_then(|p| p.try_into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn string_roundtrip() {
+        let s = "m/44H/1022H/2H/618H/1460H/3H";
+        let path: IdentityPath = s.parse().unwrap();
+        assert_eq!(path.to_string(), s);
+        assert_eq!(path, IdentityPath::new(&NetworkID::Stokenet, 3));
+        assert_eq!(path.network_id(), NetworkID::Stokenet);
+        assert_eq!(path.identity_index(), 3);
+    }
+
+    #[test]
+    fn account_path_is_for_different_entity_kind() {
+        assert_eq!(
+            "m/44H/1022H/1H/525H/1460H/0H".parse::<IdentityPath>(),
+            Err(Error::PathIsForDifferentEntityKind {
+                expected: EntityKind::Identity,
+                found: EntityKind::Account
+            })
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use strum_macros::Display;
+
+use crate::prelude::*;
+
+/// The kind of key, i.e. what the key is used for, used as the (unhardened)
+/// value of the `key_kind` path component.
+///
+/// The values are the sum of the ASCII values of the uppercased name of the
+/// kind, see `test_asciisum`.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Display, enum_iterator::Sequence,
+)]
+#[repr(u32)]
+pub enum KeyKind {
+    /// Keys used to control the entity, signing transactions (the default).
+    TransactionSigning = 1460,
+
+    /// Keys used for authentication, such as [ROLA][rola]. Used if a separate
+    /// key is created for ROLA and stored in the metadata of the entity.
+    ///
+    /// [rola]: https://docs.radixdlt.com/docs/rola-radix-off-ledger-auth
+    AuthenticationSigning = 1678,
+}
+
+impl KeyKind {
+    /// Returns a collection of all key kinds.
+    pub fn all() -> Vec<KeyKind> {
+        enum_iterator::all::<KeyKind>().collect::<Vec<_>>()
+    }
+
+    /// The unhardened value of this key kind, e.g. `1460` for `TransactionSigning`.
+    pub const fn value(&self) -> HDPathComponentValue {
+        *self as HDPathComponentValue
+    }
+
+    /// Returns `<self.value()>H`, that is, the value of the key kind
+    /// but hardened, as per SLIP10.
+    pub const fn hardened_hd_component_value(&self) -> HDPathComponentValue {
+        harden(self.value())
+    }
+
+    /// Tries to create a `KeyKind` from its unhardened `value`, e.g. `1460`.
+    pub fn from_value(value: HDPathComponentValue) -> Result<Self> {
+        Self::all()
+            .into_iter()
+            .find(|k| k.value() == value)
+            .ok_or(Error::UnknownKeyKind(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn value_roundtrip() {
+        for kind in KeyKind::all() {
+            assert_eq!(KeyKind::from_value(kind.value()), Ok(kind));
+        }
+        assert_eq!(KeyKind::TransactionSigning.value(), 1460);
+        assert_eq!(
+            KeyKind::AuthenticationSigning.hardened_hd_component_value(),
+            harden(1678)
+        );
+        assert_eq!(KeyKind::from_value(525), Err(Error::UnknownKeyKind(525)));
+    }
+}
@@ -0,0 +1,302 @@
+use crate::prelude::*;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use ed25519_dalek::{PublicKey, SecretKey};
+use rand_core::{CryptoRng, OsRng, RngCore};
+use serde_json::{json, Value};
+use zeroize::Zeroizing;
+
+/// The version of the keystore JSON format, see [`Account::to_encrypted_keystore`].
+pub const KEYSTORE_VERSION: u64 = 1;
+
+/// The scrypt cost parameter `N = 2^18` used when encrypting, the same as the
+/// "standard" parameters of Ethereum's V3 keystores.
+const SCRYPT_LOG_N: u8 = 18;
+/// The largest scrypt cost accepted when decrypting, `N = 2^20`, needing 1 GiB
+/// of memory. `N` is read from the untrusted keystore, a larger one could make
+/// decrypting hang or abort before the password is even checked.
+const MAX_SCRYPT_LOG_N: u8 = 20;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const KEY_LEN: usize = 32;
+
+impl Account {
+    /// Encrypts the private key of this account with `password`, returning a
+    /// JSON keystore, similar to Ethereum's V3 keystore format but adapted for
+    /// Ed25519: the symmetric key is derived with scrypt (`N = 2^18, r = 8, p = 1`)
+    /// from `password` and a random salt, and the private key is encrypted with
+    /// AES-256-GCM, authenticating the address, path and FactorSourceID too.
+    ///
+    /// Decrypt it with [`Account::from_encrypted_keystore`].
+    ///
+    /// Not run as a doctest, since the scrypt cost makes it slow in debug
+    /// builds, the round trip is tested by the unit tests with a lower cost.
+    ///
+    /// ```no_run
+    /// extern crate wallet_compatible_derivation;
+    /// use wallet_compatible_derivation::prelude::*;
+    ///
+    /// let mnemonic: Mnemonic24Words = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate".parse().unwrap();
+    /// let account = Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, 0));
+    ///
+    /// let keystore = account.to_encrypted_keystore("correct horse battery staple");
+    /// let decrypted = Account::from_encrypted_keystore(&keystore, "correct horse battery staple").unwrap();
+    /// assert_eq!(decrypted.private_key.to_hex(), account.private_key.to_hex());
+    /// ```
+    pub fn to_encrypted_keystore(&self, password: impl AsRef<str>) -> String {
+        self.to_encrypted_keystore_with(password, SCRYPT_LOG_N, &mut OsRng)
+    }
+
+    /// Like `to_encrypted_keystore`, but with the scrypt cost `2^log_n` and
+    /// salt and nonce sampled from `rng`.
+    pub(crate) fn to_encrypted_keystore_with<R: RngCore + CryptoRng>(
+        &self,
+        password: impl AsRef<str>,
+        log_n: u8,
+        rng: &mut R,
+    ) -> String {
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill_bytes(&mut nonce);
+
+        let path = self.path.to_string();
+        let factor_source_id = self.factor_source_id.to_hex();
+        let key = derive_keystore_key(password.as_ref(), &salt, log_n)
+            .expect("Should always be able to derive key with constant scrypt params.");
+        let ciphertext = Aes256Gcm::new(key.as_ref().into())
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: self.private_key.as_bytes(),
+                    aad: associated_data(&self.address, &path, &factor_source_id).as_bytes(),
+                },
+            )
+            .expect("Should always be able to encrypt 32 bytes.");
+
+        json!({
+            "version": KEYSTORE_VERSION,
+            "address": self.address,
+            "path": path,
+            "factor_source_id": factor_source_id,
+            "crypto": {
+                "curve": "ed25519",
+                "cipher": "aes-256-gcm",
+                "cipherparams": { "nonce": hex::encode(nonce) },
+                "ciphertext": hex::encode(ciphertext),
+                "kdf": "scrypt",
+                "kdfparams": {
+                    "dklen": KEY_LEN,
+                    "n": 1u64 << log_n,
+                    "r": SCRYPT_R,
+                    "p": SCRYPT_P,
+                    "salt": hex::encode(salt),
+                },
+            },
+        })
+        .to_string()
+    }
+
+    /// Decrypts a JSON keystore created by [`Account::to_encrypted_keystore`]
+    /// using `password`, and verifies that the decrypted private key controls
+    /// the address of the keystore.
+    ///
+    /// Returns `Err(Error::KeystoreDecryptionFailed)` if `password` is wrong or
+    /// the keystore has been tampered with, and `Err(Error::InvalidKeystore)` if
+    /// `json` is not a keystore of a supported version.
+    pub fn from_encrypted_keystore(json: &str, password: impl AsRef<str>) -> Result<Self> {
+        let keystore = serde_json::from_str::<Value>(json)
+            .map_err(|e| Error::InvalidKeystore(e.to_string()))?;
+        if keystore["version"].as_u64() != Some(KEYSTORE_VERSION) {
+            return Err(Error::InvalidKeystore(format!(
+                "unsupported version, expected {KEYSTORE_VERSION}"
+            )));
+        }
+        let crypto = &keystore["crypto"];
+        let kdfparams = &crypto["kdfparams"];
+        if crypto["cipher"] != "aes-256-gcm"
+            || crypto["kdf"] != "scrypt"
+            || crypto["curve"] != "ed25519"
+            || kdfparams["dklen"].as_u64() != Some(KEY_LEN as u64)
+            || kdfparams["r"].as_u64() != Some(SCRYPT_R as u64)
+            || kdfparams["p"].as_u64() != Some(SCRYPT_P as u64)
+        {
+            return Err(Error::InvalidKeystore(
+                "unsupported cipher, curve or KDF".to_owned(),
+            ));
+        }
+        let log_n = kdfparams["n"]
+            .as_u64()
+            .filter(|n| n.is_power_of_two() && *n > 1)
+            .map(|n| n.trailing_zeros() as u8)
+            .filter(|log_n| *log_n <= MAX_SCRYPT_LOG_N)
+            .ok_or_else(|| Error::InvalidKeystore("invalid scrypt 'n'".to_owned()))?;
+
+        let address = string_field(&keystore, "address")?;
+        let path_string = string_field(&keystore, "path")?;
+        let factor_source_id_hex = string_field(&keystore, "factor_source_id")?;
+        let path = AccountPath::from_str(path_string)?;
+        let factor_source_id = FactorSourceID::from_str(factor_source_id_hex)
+            .map_err(|_| Error::InvalidKeystore("invalid 'factor_source_id'".to_owned()))?;
+        let salt = hex_field(&kdfparams["salt"], "salt")?;
+        let nonce = hex_field(&crypto["cipherparams"]["nonce"], "nonce")?;
+        let ciphertext = hex_field(&crypto["ciphertext"], "ciphertext")?;
+        if nonce.len() != NONCE_LEN {
+            return Err(Error::InvalidKeystore("invalid 'nonce'".to_owned()));
+        }
+
+        let key = derive_keystore_key(password.as_ref(), &salt, log_n)?;
+        let plaintext = Aes256Gcm::new(key.as_ref().into())
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: associated_data(address, path_string, factor_source_id_hex).as_bytes(),
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| Error::KeystoreDecryptionFailed)?;
+
+        let private_key =
+            SecretKey::from_bytes(&plaintext).map_err(|_| Error::KeystoreDecryptionFailed)?;
+        let public_key = PublicKey::from(&private_key);
+        let network_id = path.network_id();
+        if derive_address(&public_key, &network_id)? != address {
+            return Err(Error::InvalidKeystore(
+                "private key does not control 'address'".to_owned(),
+            ));
+        }
+        Ok(Self {
+            network_id,
+            private_key,
+            public_key,
+            address: address.to_owned(),
+            index: path.account_index(),
+            path,
+            factor_source_id,
+        })
+    }
+}
+
+/// Derives the 32 bytes symmetric key from `password` and `salt` using scrypt
+/// with the cost `2^log_n`, zeroized when dropped.
+fn derive_keystore_key(password: &str, salt: &[u8], log_n: u8) -> Result<Zeroizing<[u8; KEY_LEN]>> {
+    let params = scrypt::Params::new(log_n, SCRYPT_R, SCRYPT_P, KEY_LEN)
+        .map_err(|e| Error::InvalidKeystore(e.to_string()))?;
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    scrypt::scrypt(password.as_bytes(), salt, &params, key.as_mut())
+        .map_err(|e| Error::InvalidKeystore(e.to_string()))?;
+    Ok(key)
+}
+
+/// The non secret fields of the keystore which are authenticated, but not
+/// encrypted, by AES-GCM.
+fn associated_data(address: &str, path: &str, factor_source_id: &str) -> String {
+    [address, path, factor_source_id].join(",")
+}
+
+fn string_field<'a>(keystore: &'a Value, name: &str) -> Result<&'a str> {
+    keystore[name]
+        .as_str()
+        .ok_or_else(|| Error::InvalidKeystore(format!("missing '{name}'")))
+}
+
+fn hex_field(value: &Value, name: &str) -> Result<Vec<u8>> {
+    value
+        .as_str()
+        .and_then(|s| hex::decode(s).ok())
+        .ok_or_else(|| Error::InvalidKeystore(format!("invalid '{name}'")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+    /// Tests use a low scrypt cost, the default is slow in debug builds.
+    const TEST_LOG_N: u8 = 10;
+
+    fn keystore(password: &str) -> String {
+        Account::test_0(&NetworkID::Mainnet, 0).to_encrypted_keystore_with(
+            password,
+            TEST_LOG_N,
+            &mut ChaCha20Rng::from_seed([0u8; 32]),
+        )
+    }
+
+    #[test]
+    fn roundtrip() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let keystore = keystore("radix");
+        assert!(!keystore.contains(&account.private_key.to_hex()));
+
+        let decrypted = Account::from_encrypted_keystore(&keystore, "radix").unwrap();
+        assert_eq!(
+            decrypted.to_string_include_private_key(true),
+            account.to_string_include_private_key(true)
+        );
+    }
+
+    #[test]
+    #[ignore = "slow in debug builds, run with `cargo test --release -- --ignored`"]
+    fn roundtrip_default_params() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let keystore = account.to_encrypted_keystore("radix");
+        let decrypted = Account::from_encrypted_keystore(&keystore, "radix").unwrap();
+        assert_eq!(decrypted.private_key.to_hex(), account.private_key.to_hex());
+    }
+
+    #[test]
+    fn wrong_password_is_err() {
+        assert_eq!(
+            Account::from_encrypted_keystore(&keystore("radix"), "Radix").err(),
+            Some(Error::KeystoreDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn tampered_path_is_err() {
+        let keystore = keystore("radix").replace("1460H/0H", "1460H/1H");
+        assert_eq!(
+            Account::from_encrypted_keystore(&keystore, "radix").err(),
+            Some(Error::KeystoreDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn too_large_scrypt_n_is_err() {
+        let with_n = |n: u64| {
+            let mut keystore = serde_json::from_str::<Value>(&keystore("radix")).unwrap();
+            keystore["crypto"]["kdfparams"]["n"] = n.into();
+            keystore.to_string()
+        };
+        assert_eq!(
+            Account::from_encrypted_keystore(&with_n(1 << 40), "radix").err(),
+            Some(Error::InvalidKeystore("invalid scrypt 'n'".to_owned()))
+        );
+        assert_eq!(
+            Account::from_encrypted_keystore(&with_n(1 << (MAX_SCRYPT_LOG_N + 1)), "radix").err(),
+            Some(Error::InvalidKeystore("invalid scrypt 'n'".to_owned()))
+        );
+        // Within the cap `n` is accepted, and only fails authentication.
+        assert_eq!(
+            Account::from_encrypted_keystore(&with_n(1 << (TEST_LOG_N + 1)), "radix").err(),
+            Some(Error::KeystoreDecryptionFailed)
+        );
+    }
+
+    #[test]
+    fn not_a_keystore_is_err() {
+        assert!(matches!(
+            Account::from_encrypted_keystore(r#"{ "version": 3 }"#, "radix"),
+            Err(Error::InvalidKeystore(_))
+        ));
+        assert!(matches!(
+            Account::from_encrypted_keystore("not json", "radix"),
+            Err(Error::InvalidKeystore(_))
+        ));
+    }
+}
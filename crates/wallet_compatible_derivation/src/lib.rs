@@ -14,15 +14,15 @@
 //!
 //! // Create an hierarchical deterministic derivation path.
 //! let path = AccountPath::new(
-//!	    &NetworkID::Mainnet, // Mainnet or Stokenet (testnet)
-//!	    0 // Account Index, 0 is first.
+//!     &NetworkID::Mainnet, // Mainnet or Stokenet (testnet)
+//!     0 // Account Index, 0 is first.
 //! );
 //!
 //! // 24 word BIP-39 English mnemonic
 //! let mnemonic: Mnemonic24Words = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate".parse().unwrap();
 //!
 //! // Derive Babylon Radix account...
-//! let account = Account::derive(&mnemonic, "radix", &path);
+//! let account = Account::derive_unwrap(&mnemonic, "radix", &path);
 //!
 //! // ... containing the Account Address
 //! assert_eq!(account.address, "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8");
@@ -32,39 +32,107 @@
 //!
 //! // continue with next index, `1` to derive next account
 //! let path_of_next_account = AccountPath::new(
-//! 	&NetworkID::Mainnet,
-//! 	1 // `1` comes after `0` (breaking news!)
+//!     &NetworkID::Mainnet,
+//!     1 // `1` comes after `0` (breaking news!)
 //! );
-//! let second_account = Account::derive(
-//! 	&mnemonic,
-//! 	"radix",
-//! 	&path_of_next_account
+//! let second_account = Account::derive_unwrap(
+//!     &mnemonic,
+//!     "radix",
+//!     &path_of_next_account
 //! );
 //! // Next address...
 //! assert_eq!(second_account.address, "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69");
 //! ```
 //!
 mod account;
+mod account_bytes;
 mod account_path;
+mod account_with_rola;
 mod bip32_path;
+mod canonical_account_record;
+#[cfg(feature = "cffi")]
+mod cffi;
+mod curve_scheme;
+mod derivation_request;
 mod derive_account_address;
 mod derive_key_pair;
+mod derived_entities;
+mod entity_kind;
 mod error;
 mod factor_source_id;
+mod gap_scan;
+#[cfg(feature = "gateway")]
+mod gateway;
+mod identity_path;
+mod key_kind;
+#[cfg(feature = "keystore")]
+mod keystore;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod mnemonic;
 mod mnemonic_24words;
 mod network_id;
+mod olympia_account;
+mod olympia_account_path;
+mod olympia_address;
+mod passphrase;
+mod persona;
+mod securified_account_path;
+mod seed;
 mod to_hex;
+mod verify_batch;
+#[cfg(feature = "wasm")]
+mod wasm;
+mod watch_only_account;
+mod write_csv;
 
 pub mod prelude {
     pub use crate::account::*;
+    pub use crate::account_bytes::*;
     pub use crate::account_path::*;
+    pub use crate::account_with_rola::*;
     pub use crate::bip32_path::*;
+    pub use crate::canonical_account_record::*;
+    #[cfg(feature = "cffi")]
+    pub use crate::cffi::*;
+    pub use crate::curve_scheme::*;
+    pub use crate::derivation_request::*;
+    pub use crate::derived_entities::*;
 
+    pub use crate::entity_kind::*;
     pub use crate::error::*;
     pub use crate::factor_source_id::*;
+    pub use crate::gap_scan::*;
+    #[cfg(feature = "gateway")]
+    pub use crate::gateway::*;
+    pub use crate::identity_path::*;
+    pub use crate::key_kind::*;
+    #[cfg(feature = "keystore")]
+    pub use crate::keystore::*;
+    #[cfg(feature = "metrics")]
+    pub use crate::metrics::*;
+    pub use crate::mnemonic::*;
     pub use crate::mnemonic_24words::*;
     pub use crate::network_id::*;
+    pub use crate::olympia_account::*;
+    pub use crate::olympia_account_path::*;
+    pub use crate::olympia_address::*;
+    pub use crate::passphrase::*;
+    pub use crate::persona::*;
+    pub use crate::securified_account_path::*;
+    pub use crate::seed::*;
     pub use crate::to_hex::*;
+    pub use crate::verify_batch::*;
+    #[cfg(feature = "wasm")]
+    pub use crate::wasm::*;
+    pub use crate::watch_only_account::*;
+    pub use crate::write_csv::*;
+
+    // Re-exported so that integrators use the same version of `ed25519_dalek`
+    // as this crate, when working with the keys of an `Account`.
+    pub use ed25519_dalek;
+    pub use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signature, Signer, Verifier};
+    pub use radix_common::prelude::Ed25519PublicKey;
 
     pub(crate) use crate::derive_account_address::*;
     pub(crate) use crate::derive_key_pair::*;
@@ -0,0 +1,92 @@
+use crate::prelude::*;
+
+use std::ops::Range;
+use std::time::Duration;
+
+/// Statistics of a derivation, e.g. to log the throughput of a batch service,
+/// see [`Account::derive_range_with_stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DerivationStats {
+    /// The number of derived accounts.
+    pub count: usize,
+
+    /// Time spent computing the BIP-39 seed from the mnemonic and passphrase,
+    /// which is done once, and is slow by design (PBKDF2 with 2048 rounds).
+    pub seed_derivation: Duration,
+
+    /// Total time spent deriving the keys, and addresses, of all accounts.
+    pub key_derivation: Duration,
+}
+
+impl DerivationStats {
+    /// The total time spent deriving the seed and all accounts.
+    pub fn total(&self) -> Duration {
+        self.seed_derivation + self.key_derivation
+    }
+
+    /// The number of accounts derived per second, excluding the time spent
+    /// deriving the seed, or `0.0` if no time was spent at all.
+    pub fn accounts_per_second(&self) -> f64 {
+        let seconds = self.key_derivation.as_secs_f64();
+        if seconds == 0.0 {
+            0.0
+        } else {
+            self.count as f64 / seconds
+        }
+    }
+}
+
+impl Account {
+    /// Like `derive_range` but also returns the [`DerivationStats`] of the
+    /// derivation, measuring the time spent deriving the seed and the keys.
+    pub fn derive_range_with_stats(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        network_id: &NetworkID,
+        range: Range<EntityIndex>,
+    ) -> Result<(Vec<Self>, DerivationStats)> {
+        let mut stats = DerivationStats::default();
+        let accounts = Self::derive_range_instrumented(
+            mnemonic,
+            passphrase,
+            network_id,
+            range,
+            || false,
+            Some(&mut stats),
+        )?;
+        Ok((accounts, stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use std::time::Duration;
+
+    #[test]
+    fn count_eq_number_derived() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_0());
+        let (accounts, stats) =
+            Account::derive_range_with_stats(&mnemonic, "", &NetworkID::Mainnet, 3..13).unwrap();
+        assert_eq!(stats.count, 10);
+        assert_eq!(stats.count, accounts.len());
+        assert!(stats.seed_derivation > Duration::ZERO);
+        assert_eq!(stats.total(), stats.seed_derivation + stats.key_derivation);
+        assert_eq!(
+            accounts[0].address,
+            Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, 3))
+                .address
+        );
+    }
+
+    #[test]
+    fn accounts_per_second() {
+        let stats = DerivationStats {
+            count: 500,
+            seed_derivation: Duration::from_secs(1),
+            key_derivation: Duration::from_millis(250),
+        };
+        assert_eq!(stats.accounts_per_second(), 2000.0);
+        assert_eq!(DerivationStats::default().accounts_per_second(), 0.0);
+    }
+}
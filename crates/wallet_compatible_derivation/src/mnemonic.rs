@@ -0,0 +1,268 @@
+use crate::prelude::*;
+use zeroize::Zeroizing;
+
+/// A BIP-39 mnemonic of any of the standard lengths: 12, 15, 18, 21 or 24 words.
+///
+/// Holds the BIP-39 entropy - 16, 20, 24, 28 or 32 bytes - in a fixed size buffer
+/// which is zeroized in full regardless of the length, and the language of the
+/// words, which matters since the BIP-39 seed is derived from the words.
+///
+/// See [`Mnemonic24Words`] if you want to only allow 24 words mnemonics.
+#[derive(Clone, PartialEq, Eq, derive_more::Display, ZeroizeOnDrop, Zeroize)]
+#[display("{}", self.phrase())]
+pub struct Mnemonic {
+    pub(crate) entropy: [u8; 32],
+    #[zeroize(skip)]
+    entropy_len: usize,
+    #[zeroize(skip)]
+    language: bip39::Language,
+}
+
+impl std::fmt::Debug for Mnemonic {
+    /// Redacts the entropy, so that the mnemonic never ends up in logs, use
+    /// `phrase` if you really need it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Mnemonic(***redacted***)")
+    }
+}
+
+impl Mnemonic {
+    /// Converts a `bip39` crate `Mnemonic` into a `Mnemonic` preserving its
+    /// language, so that `phrase` returns the words in the original language, and
+    /// `to_seed` returns the same seed as the `bip39` crate does.
+    pub fn from_bip39(mnemonic: bip39::Mnemonic) -> Result<Self> {
+        let (mut array, entropy_len) = mnemonic.to_entropy_array();
+        if entropy_len > 32 {
+            array.zeroize();
+            return Err(Error::InvalidMnemonic);
+        }
+        let result = Self::from_entropy_in(mnemonic.language(), &array[..entropy_len]);
+        array.zeroize();
+        Ok(result)
+    }
+
+    /// `entropy` MUST be 16, 20, 24, 28 or 32 bytes.
+    pub(crate) fn from_entropy_in(language: bip39::Language, entropy: &[u8]) -> Self {
+        let mut buffer = [0u8; 32];
+        buffer[..entropy.len()].copy_from_slice(entropy);
+        Self {
+            entropy: buffer,
+            entropy_len: entropy.len(),
+            language,
+        }
+    }
+
+    /// The language of the words of this mnemonic.
+    pub fn language(&self) -> bip39::Language {
+        self.language
+    }
+
+    /// The number of words of this mnemonic, 12, 15, 18, 21 or 24.
+    pub fn word_count(&self) -> usize {
+        self.entropy_len * 3 / 4
+    }
+
+    /// Formats the words as a single mnemonic phrase, with space (" ") joining
+    /// the words.
+    pub fn phrase(&self) -> String {
+        self.wrapped().to_string()
+    }
+
+    pub(crate) fn wrapped(&self) -> bip39::Mnemonic {
+        self.try_wrapped()
+            .expect("Should always be able to create a BIP-39 mnemonic.")
+    }
+
+    /// Like `wrapped` but returns an error instead of panicking if the entropy
+    /// is invalid, which can only happen if `Self` was constructed without
+    /// validation.
+    pub(crate) fn try_wrapped(&self) -> Result<bip39::Mnemonic> {
+        bip39::Mnemonic::from_entropy_in(self.language, &self.entropy[..self.entropy_len])
+            .map_err(|_| Error::InvalidMnemonic)
+    }
+
+    /// The BIP-39 entropy of this mnemonic, which is as sensitive as the
+    /// mnemonic itself, hence returned as `Zeroizing`.
+    pub fn to_entropy(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.entropy[..self.entropy_len].to_vec())
+    }
+
+    /// The hex of the BIP-39 entropy of this mnemonic, see `to_entropy`.
+    pub fn to_entropy_hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(&self.entropy[..self.entropy_len]))
+    }
+
+    pub fn is_zeroized(&self) -> bool {
+        self.entropy == [0; 32]
+    }
+
+    pub fn to_seed(&self, passphrase: impl AsRef<str>) -> [u8; 64] {
+        self.try_to_seed(passphrase)
+            .expect("Should always be able to create a BIP-39 seed.")
+    }
+
+    /// Like `to_seed` but returns an error instead of panicking if the entropy
+    /// of this mnemonic is invalid.
+    pub fn try_to_seed(&self, passphrase: impl AsRef<str>) -> Result<[u8; 64]> {
+        self.try_wrapped().map(|m| m.to_seed(passphrase.as_ref()))
+    }
+}
+
+impl TryFrom<bip39::Mnemonic> for Mnemonic {
+    type Error = crate::Error;
+
+    /// Converts a `bip39` crate `Mnemonic` into `Mnemonic`, see `from_bip39`.
+    fn try_from(value: bip39::Mnemonic) -> Result<Self> {
+        Self::from_bip39(value)
+    }
+}
+
+impl FromStr for Mnemonic {
+    type Err = crate::Error;
+
+    /// Parses a 12, 15, 18, 21 or 24 words mnemonic.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "__test_0" || s == "__test_1" {
+            return s.parse::<Mnemonic24Words>().map(Self::from);
+        }
+        Self::parse_phrase(s)
+    }
+}
+
+impl Mnemonic {
+    /// Like `from_str` but only accepts actual BIP-39 phrases, never the
+    /// `"__test_0"` and `"__test_1"` shorthands of the test mnemonics, used by
+    /// entry points for other software, such as the C ABI.
+    pub(crate) fn parse_phrase(s: &str) -> Result<Self> {
+        if s.trim().is_empty() {
+            return Err(Error::EmptyMnemonic);
+        }
+        s.parse::<bip39::Mnemonic>()
+            .map_err(|e| map_bip39_parse_error(s, e))
+            .and_then(Self::from_bip39)
+    }
+}
+
+/// Maps the error of parsing `s` as a `bip39::Mnemonic` to a specific `Error`
+/// when possible, so that users can tell a misspelled word apart from a wrong
+/// word, i.e. a checksum failure.
+pub(crate) fn map_bip39_parse_error(s: &str, error: bip39::Error) -> Error {
+    match error {
+        bip39::Error::UnknownWord(index) => Error::UnknownMnemonicWord {
+            word: s
+                .split_whitespace()
+                .nth(index)
+                .unwrap_or_default()
+                .to_owned(),
+            position: index + 1,
+        },
+        bip39::Error::InvalidChecksum => Error::InvalidMnemonicChecksum,
+        _ => Error::InvalidMnemonic,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem;
+    use std::ops::Range;
+
+    use crate::prelude::*;
+
+    const TWELVE_WORDS: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn unknown_word() {
+        assert_eq!(
+            "abandon abandon abandn abandon abandon abandon abandon abandon abandon abandon abandon about"
+                .parse::<Mnemonic>(),
+            Err(Error::UnknownMnemonicWord {
+                word: "abandn".to_owned(),
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_checksum() {
+        // Valid words, but `about` swapped for `abandon`.
+        assert_eq!(
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon"
+                .parse::<Mnemonic>(),
+            Err(Error::InvalidMnemonicChecksum)
+        );
+        assert_eq!(
+            "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo"
+                .parse::<Mnemonic24Words>(),
+            Err(Error::InvalidMnemonicChecksum)
+        );
+    }
+
+    #[test]
+    fn all_word_counts() {
+        for word_count in [12, 15, 18, 21, 24] {
+            let entropy = vec![0xff; word_count * 4 / 3];
+            let phrase = bip39::Mnemonic::from_entropy(&entropy).unwrap().to_string();
+            let sut: Mnemonic = phrase.parse().unwrap();
+            assert_eq!(sut.word_count(), word_count);
+            assert_eq!(*sut.to_entropy(), entropy);
+            assert_eq!(*sut.to_entropy_hex(), "ff".repeat(entropy.len()));
+            assert_eq!(sut.phrase(), phrase);
+        }
+    }
+
+    #[test]
+    fn empty_is_err() {
+        assert_eq!("".parse::<Mnemonic>(), Err(Error::EmptyMnemonic));
+        assert_eq!("zoo".parse::<Mnemonic>(), Err(Error::InvalidMnemonic));
+        assert_eq!(
+            Error::EmptyMnemonic.to_string(),
+            "Empty mnemonic, please input a 12, 15, 18, 21 or 24 words BIP-39 mnemonic"
+        );
+    }
+
+    #[test]
+    fn seed_of_12_words_matches_bip39() {
+        let sut: Mnemonic = TWELVE_WORDS.parse().unwrap();
+        // Test vector from https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+        assert_eq!(
+            hex::encode(sut.to_seed("TREZOR")),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    fn derive_account_from_12_words_mainnet_index_0() {
+        let mnemonic: Mnemonic = TWELVE_WORDS.parse().unwrap();
+        let account =
+            Account::derive_unwrap(&mnemonic, "", &AccountPath::new(&NetworkID::Mainnet, 0));
+        assert_eq!(
+            account.private_key.to_hex(),
+            "c6da7f25529087c811de8bcaefbfee3ef1787bef6837f9116dac242d5a3e231a"
+        );
+        assert_eq!(
+            account.public_key.to_hex(),
+            "a5f40a306cd319049700e1dbbe8e446d17146926534e389ee9365f506549678d"
+        );
+        assert_eq!(
+            account.address,
+            "account_rdx129xas2sw4fn5amyce4zun43amw73d6lu7htcxlvghd3egalnf2uv66"
+        );
+    }
+
+    #[test]
+    fn zeroize_covers_full_buffer() {
+        let mut sut: Mnemonic = TWELVE_WORDS.parse().unwrap();
+        let view = &sut.entropy as *const _ as *const u8;
+        let range = Range {
+            start: 0,
+            end: mem::size_of::<[u8; 32]>() as isize,
+        };
+        sut.entropy[16..].copy_from_slice(&[0xff; 16]);
+        sut.zeroize();
+        for i in range {
+            assert_eq!(unsafe { *view.offset(i) }, 0x00);
+        }
+        assert!(sut.is_zeroized());
+    }
+}
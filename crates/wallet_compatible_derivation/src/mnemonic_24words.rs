@@ -1,15 +1,43 @@
 use crate::prelude::*;
+use rand_core::{CryptoRng, RngCore};
+use std::ops::Deref;
+use zeroize::Zeroizing;
 
 /// A guaranteed 24 words long BIP-39 mnemonic.
 ///
-/// Holds the BIP-39 entropy - 32 bytes.
-#[derive(Debug, Clone, PartialEq, Eq, derive_more::Display, ZeroizeOnDrop, Zeroize)]
+/// A thin wrapper around [`Mnemonic`] which only allows 32 bytes of entropy, use
+/// [`Mnemonic`] if you want to support 12, 15, 18 or 21 words mnemonics too.
+#[derive(Clone, PartialEq, Eq, derive_more::Display, ZeroizeOnDrop, Zeroize)]
 #[display("{}", self.phrase())]
-pub struct Mnemonic24Words([u8; 32]);
+pub struct Mnemonic24Words(Mnemonic);
+
+impl std::fmt::Debug for Mnemonic24Words {
+    /// Redacts the entropy, so that the mnemonic never ends up in logs, use
+    /// `phrase` if you really need it.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Mnemonic24Words(***redacted***)")
+    }
+}
 
 impl Mnemonic24Words {
     pub(crate) fn new(entropy: [u8; 32]) -> Self {
-        Self(entropy)
+        Self(Mnemonic::from_entropy_in(
+            bip39::Language::English,
+            &entropy,
+        ))
+    }
+
+    /// Converts a `bip39` crate `Mnemonic` into `Mnemonic24Words` preserving its
+    /// language, so that `phrase` returns the words in the original language, and
+    /// `to_seed` returns the same seed as the `bip39` crate does. Will fail if
+    /// the word count is not 24.
+    pub fn from_bip39(mnemonic: bip39::Mnemonic) -> Result<Self> {
+        Mnemonic::from_bip39(mnemonic).and_then(Self::try_from)
+    }
+
+    /// The language of the words of this mnemonic.
+    pub fn language(&self) -> bip39::Language {
+        self.0.language()
     }
 }
 
@@ -17,19 +45,41 @@ impl TryFrom<bip39::Mnemonic> for Mnemonic24Words {
     type Error = crate::Error;
 
     /// Tries to convert a `bip39` crate `Mnemonic` into `Mnemonic24Words`,
-    /// will fail if the word count is not 24.
+    /// will fail if the word count is not 24, see `from_bip39`.
     fn try_from(value: bip39::Mnemonic) -> Result<Self> {
+        Self::from_bip39(value)
+    }
+}
+
+impl TryFrom<Mnemonic> for Mnemonic24Words {
+    type Error = crate::Error;
+
+    /// Tries to convert a `Mnemonic` into `Mnemonic24Words`, will fail if the
+    /// word count is not 24.
+    fn try_from(value: Mnemonic) -> Result<Self> {
         if value.word_count() != Self::WORD_COUNT {
             return Err(Error::UnsupportedMnemonicTooFewWords {
                 expected: Self::WORD_COUNT,
                 found: value.word_count(),
             });
         }
-        value
-            .to_entropy()
-            .try_into()
-            .map_err(|_| Error::InvalidMnemonic)
-            .map(|v| Self::new(v))
+        Ok(Self(value))
+    }
+}
+
+impl From<Mnemonic24Words> for Mnemonic {
+    fn from(value: Mnemonic24Words) -> Self {
+        value.0.clone()
+    }
+}
+
+impl Deref for Mnemonic24Words {
+    type Target = Mnemonic;
+
+    /// Allows passing a `&Mnemonic24Words` wherever a `&Mnemonic` is expected,
+    /// e.g. to `Account::derive`.
+    fn deref(&self) -> &Mnemonic {
+        &self.0
     }
 }
 
@@ -37,19 +87,121 @@ impl Mnemonic24Words {
     /// Formats 24 words as a single mnemonic phrase, with space (" ") joining
     /// the words.
     pub fn phrase(&self) -> String {
-        self.wrapped().to_string()
+        self.0.phrase()
     }
 
+    #[cfg(test)]
     fn wrapped(&self) -> bip39::Mnemonic {
-        bip39::Mnemonic::from_entropy(self.0.as_slice())
-            .expect("Should always be able to create a BIP-39 mnemonic.")
+        self.0.wrapped()
+    }
+
+    #[cfg(test)]
+    fn try_wrapped(&self) -> Result<bip39::Mnemonic> {
+        self.0.try_wrapped()
+    }
+
+    /// The 32 bytes of BIP-39 entropy of this mnemonic, which is as sensitive
+    /// as the mnemonic itself, hence returned as `Zeroizing`.
+    pub fn to_entropy(&self) -> Zeroizing<[u8; 32]> {
+        Zeroizing::new(self.0.entropy)
+    }
+
+    /// The hex of the 32 bytes of BIP-39 entropy of this mnemonic, e.g. to
+    /// exchange with other tools, which is as sensitive as the mnemonic itself,
+    /// hence returned as `Zeroizing`.
+    pub fn to_entropy_hex(&self) -> Zeroizing<String> {
+        Zeroizing::new(hex::encode(*self.to_entropy()))
+    }
+
+    /// The 24 words mnemonic of the 32 bytes of BIP-39 entropy encoded as hex,
+    /// the inverse of `to_entropy_hex`, returning `Err` if `entropy_hex` is not
+    /// exactly 64 hex characters. The decoded entropy is zeroized, also on error.
+    pub fn from_entropy_hex(entropy_hex: &str) -> Result<Self> {
+        let mut entropy = Zeroizing::new([0u8; 32]);
+        hex::decode_to_slice(entropy_hex, entropy.as_mut())
+            .map_err(|_| Error::InvalidEntropyHex)?;
+        Ok(Self::new(*entropy))
     }
 
     pub fn is_zeroized(&self) -> bool {
-        self.0 == [0; 32]
+        self.0.is_zeroized()
+    }
+
+    /// Suggests corrections of the words in `phrase` which are not English
+    /// BIP-39 words, e.g. misspelled words, returning the position of each such
+    /// word, starting at `1` like in [`Error::UnknownMnemonicWord`], together
+    /// with the closest BIP-39 words, those within a Levenshtein distance of `2`,
+    /// closest first.
+    ///
+    /// A word might have no suggestions, and the suggestions are not checked
+    /// against the checksum of the mnemonic.
+    pub fn suggest_corrections(phrase: &str) -> Vec<(usize, Vec<String>)> {
+        let english = bip39::Language::English;
+        phrase
+            .split_whitespace()
+            .enumerate()
+            .filter(|(_, word)| english.find_word(word).is_none())
+            .map(|(index, word)| {
+                let word = word.to_lowercase();
+                let mut candidates = english
+                    .word_list()
+                    .iter()
+                    .map(|candidate| (levenshtein_distance(&word, candidate), *candidate))
+                    .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+                    .collect::<Vec<_>>();
+                // Stable, so words at equal distance stay alphabetically ordered.
+                candidates.sort_by_key(|(distance, _)| *distance);
+                let suggestions = candidates
+                    .into_iter()
+                    .map(|(_, candidate)| candidate.to_owned())
+                    .collect();
+                (index + 1, suggestions)
+            })
+            .collect()
+    }
+
+    /// Generates a new 24 words mnemonic, using 32 bytes of entropy sampled
+    /// from the cryptographically secure `rng`, e.g. `rand_core::OsRng`.
+    ///
+    /// The caller provides the `rng`, so that this crate never chooses a source
+    /// of randomness, see `generate_with_rng` to pass it by reference.
+    pub fn generate(mut rng: impl RngCore + CryptoRng) -> Self {
+        Self::generate_with_rng(&mut rng)
+    }
+
+    /// Generates a new 24 words mnemonic, using 32 bytes of entropy
+    /// sampled from the cryptographically secure `rng`.
+    ///
+    /// The mapping from entropy to words is fully deterministic, so the
+    /// same `rng` state always yields the same mnemonic.
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let mut entropy = [0u8; 32];
+        rng.fill_bytes(&mut entropy);
+        let mnemonic = Self::new(entropy);
+        entropy.zeroize();
+        mnemonic
     }
 }
 
+/// The largest Levenshtein distance of suggestions of `suggest_corrections`.
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// The number of single character insertions, deletions or substitutions
+/// needed to change `a` into `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 pub(crate) trait TestValue {
     fn test_0() -> Self;
     fn test_1() -> Self;
@@ -68,7 +220,13 @@ impl TestValue for Mnemonic24Words {
 impl Mnemonic24Words {
     pub const WORD_COUNT: usize = 24;
     pub fn to_seed(&self, passphrase: impl AsRef<str>) -> [u8; 64] {
-        self.wrapped().to_seed(passphrase.as_ref())
+        self.0.to_seed(passphrase)
+    }
+
+    /// Like `to_seed` but returns an error instead of panicking if the entropy
+    /// of this mnemonic is invalid.
+    pub fn try_to_seed(&self, passphrase: impl AsRef<str>) -> Result<[u8; 64]> {
+        self.0.try_to_seed(passphrase)
     }
 }
 
@@ -82,8 +240,11 @@ impl FromStr for Mnemonic24Words {
         if s == "__test_1" {
             return Ok(Self::test_1());
         }
+        if s.trim().is_empty() {
+            return Err(Error::EmptyMnemonic);
+        }
         s.parse::<bip39::Mnemonic>()
-            .map_err(|_| Error::InvalidMnemonic)
+            .map_err(|e| map_bip39_parse_error(s, e))
             .and_then(|m| m.try_into())
     }
 }
@@ -94,6 +255,7 @@ mod tests {
     use std::ops::Range;
 
     use crate::prelude::*;
+    use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
     #[test]
     fn word_count_of_12_disallowed() {
@@ -109,12 +271,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn empty_is_err() {
+        assert_eq!("".parse::<Mnemonic24Words>(), Err(Error::EmptyMnemonic));
+        assert_eq!(" \n ".parse::<Mnemonic24Words>(), Err(Error::EmptyMnemonic));
+        assert_eq!(
+            "zoo".parse::<Mnemonic24Words>(),
+            Err(Error::InvalidMnemonic)
+        );
+    }
+
     #[test]
     fn word_count_of_24_works() {
         let s = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
         assert_eq!(s.parse::<Mnemonic24Words>().unwrap().to_string(), s);
     }
 
+    #[test]
+    fn debug_is_redacted() {
+        let sut = Mnemonic24Words::test_0();
+        let debug = format!("{sut:?}");
+        assert_eq!(debug, "Mnemonic24Words(***redacted***)");
+        assert!(!debug.contains("bright"));
+        assert_eq!(
+            format!("{:?}", Mnemonic::from(sut)),
+            "Mnemonic(***redacted***)"
+        );
+    }
+
     #[test]
     fn test_0_parse() {
         let sut: Mnemonic24Words = "__test_0".parse().unwrap();
@@ -136,6 +320,149 @@ mod tests {
         );
     }
 
+    #[test]
+    fn entropy_hex_all_ff_is_zoo_vote() {
+        let sut = Mnemonic24Words::from_entropy_hex(&"ff".repeat(32)).unwrap();
+        assert_eq!(sut.phrase(), "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote");
+        assert_eq!(*sut.to_entropy_hex(), "ff".repeat(32));
+    }
+
+    #[test]
+    fn entropy_hex_roundtrip() {
+        for sut in [Mnemonic24Words::test_0(), Mnemonic24Words::test_1()] {
+            assert_eq!(
+                Mnemonic24Words::from_entropy_hex(&sut.to_entropy_hex()).unwrap(),
+                sut
+            );
+        }
+        let upper = Mnemonic24Words::test_0().to_entropy_hex().to_uppercase();
+        assert_eq!(
+            Mnemonic24Words::from_entropy_hex(&upper).unwrap(),
+            Mnemonic24Words::test_0()
+        );
+    }
+
+    #[test]
+    fn from_entropy_hex_invalid() {
+        for invalid in [
+            "".to_owned(),
+            "ff".repeat(16),
+            "ff".repeat(33),
+            format!("{}f", "ff".repeat(31)),
+            format!("{}zz", "ff".repeat(31)),
+        ] {
+            assert_eq!(
+                Mnemonic24Words::from_entropy_hex(&invalid),
+                Err(Error::InvalidEntropyHex)
+            );
+        }
+    }
+
+    #[test]
+    fn suggest_corrections() {
+        let phrase = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate";
+        assert!(Mnemonic24Words::suggest_corrections(phrase).is_empty());
+
+        let misspelled = phrase
+            .replace("bacon", "abandom")
+            .replace("video", "vdeo")
+            .replace("mask", "xxxxxxx");
+        let suggestions = Mnemonic24Words::suggest_corrections(&misspelled);
+        assert_eq!(
+            suggestions.iter().map(|(p, _)| *p).collect::<Vec<_>>(),
+            vec![3, 16, 20]
+        );
+        // `random` is at distance 2.
+        assert_eq!(suggestions[0].1, vec!["abandon", "random"]);
+        assert_eq!(suggestions[1].1[0], "video");
+        assert!(suggestions[1].1.len() > 1);
+        assert!(suggestions[2].1.is_empty());
+    }
+
+    #[test]
+    fn levenshtein_distance() {
+        assert_eq!(super::levenshtein_distance("abandom", "abandon"), 1);
+        assert_eq!(super::levenshtein_distance("kitten", "sitting"), 3);
+        assert_eq!(super::levenshtein_distance("", "zoo"), 3);
+        assert_eq!(super::levenshtein_distance("zoo", "zoo"), 0);
+    }
+
+    #[test]
+    fn generate_with_seeded_rng() {
+        let sut = Mnemonic24Words::generate(ChaCha20Rng::from_seed([0x2a; 32]));
+        assert_eq!(
+            sut,
+            Mnemonic24Words::generate(ChaCha20Rng::from_seed([0x2a; 32]))
+        );
+        assert_ne!(
+            sut,
+            Mnemonic24Words::generate(ChaCha20Rng::from_seed([0x2b; 32]))
+        );
+        let phrase = sut.phrase();
+        assert_eq!(phrase.split(' ').count(), 24);
+        assert_eq!(phrase.parse::<Mnemonic24Words>(), Ok(sut));
+    }
+
+    #[test]
+    fn generate_with_seeded_rng_is_deterministic() {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let sut = Mnemonic24Words::generate_with_rng(&mut rng);
+        // First 32 bytes of the ChaCha20 keystream for the all zero key and nonce.
+        assert_eq!(
+            hex::encode(sut.wrapped().to_entropy()),
+            "76b8e0ada0f13d90405d6ae55386bd28bdd219b8a08ded1aa836efcc8b770dc7"
+        );
+        assert_eq!(sut.to_string(), "issue shove clock draft because sight accident pull torch order quantum fabric tag art tip balance unfair stem asset test silly romance damage violin");
+    }
+
+    #[test]
+    fn to_entropy() {
+        assert_eq!(*Mnemonic24Words::test_1().to_entropy(), [0xff; 32]);
+        assert_eq!(
+            hex::encode(*Mnemonic24Words::test_0().to_entropy()),
+            hex::encode(Mnemonic24Words::test_0().wrapped().to_entropy())
+        );
+    }
+
+    #[test]
+    fn try_to_seed_eq_to_seed() {
+        let sut = Mnemonic24Words::test_0();
+        assert_eq!(sut.try_to_seed("radix").unwrap(), sut.to_seed("radix"));
+        assert_eq!(sut.try_wrapped().unwrap(), sut.wrapped());
+    }
+
+    #[test]
+    fn from_bip39_preserves_language() {
+        let japanese =
+            bip39::Mnemonic::from_entropy_in(bip39::Language::Japanese, &[0xff; 32]).unwrap();
+        let sut = Mnemonic24Words::from_bip39(japanese.clone()).unwrap();
+        assert_eq!(sut.language(), bip39::Language::Japanese);
+        assert_eq!(sut.phrase(), japanese.to_string());
+        assert_eq!(sut.to_seed("radix"), japanese.to_seed("radix"));
+        assert_eq!(*sut.to_entropy(), [0xff; 32]);
+
+        let english = Mnemonic24Words::test_1();
+        assert_eq!(english.language(), bip39::Language::English);
+        assert_ne!(sut, english);
+        assert_ne!(sut.to_seed("radix"), english.to_seed("radix"));
+    }
+
+    #[test]
+    fn twelve_words_mnemonic_is_not_24_words() {
+        let mnemonic: Mnemonic = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo wrong"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            Mnemonic24Words::try_from(mnemonic),
+            Err(Error::UnsupportedMnemonicTooFewWords {
+                expected: 24,
+                found: 12
+            })
+        );
+        let sut = Mnemonic24Words::test_0();
+        assert_eq!(Mnemonic::from(sut.clone()).phrase(), sut.phrase());
+    }
+
     #[test]
     fn zeroize() {
         let mut mnemonic = Mnemonic24Words::new([
@@ -144,8 +471,8 @@ mod tests {
             0xff, 0xff, 0xff, 0xff,
         ]);
 
-        let view = &mnemonic as *const _ as *const u8;
-        let end = mem::size_of::<Mnemonic24Words>() as isize;
+        let view = &mnemonic.0.entropy as *const _ as *const u8;
+        let end = mem::size_of::<[u8; 32]>() as isize;
         let range = Range { start: 0, end };
         for i in range.clone() {
             assert_eq!(unsafe { *view.offset(i) }, 0xff);
@@ -1,5 +1,5 @@
-use radix_common::prelude::NetworkDefinition;
-use strum_macros::{Display, EnumString};
+use radix_common::prelude::{AddressBech32Decoder, NetworkDefinition};
+use strum_macros::Display;
 
 use crate::prelude::*;
 
@@ -9,17 +9,14 @@ use crate::prelude::*;
 /// See [Babylon-node repo][node] for more details.
 ///
 /// [node]: https://github.com/radixdlt/babylon-node/blob/main/common/src/main/java/com/radixdlt/networks/Network.java#L82-L98
-#[derive(
-    Clone, Debug, PartialEq, Eq, PartialOrd, Ord, EnumString, Display, enum_iterator::Sequence,
-)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Display, enum_iterator::Sequence)]
+#[repr(u8)]
 pub enum NetworkID {
     /// The Radix mainnet.
-    #[strum(ascii_case_insensitive)]
-    Mainnet,
+    Mainnet = 0x01,
 
     /// A public facing testnet.
-    #[strum(ascii_case_insensitive)]
-    Stokenet,
+    Stokenet = 0x02,
 }
 
 impl NetworkID {
@@ -35,12 +32,66 @@ impl NetworkID {
     }
 }
 
+impl TryFrom<u8> for NetworkID {
+    type Error = Error;
+
+    /// Tries to create a `NetworkID` from its discriminant, the raw byte
+    /// identifying the network, e.g. `0x01` for `Mainnet`, see [`NetworkID::discriminant`].
+    ///
+    /// N.B. this is distinct from `TryFrom<HDPathComponentValue>` which interprets
+    /// an *unhardened path component value*, even if the two currently coincide
+    /// for all supported networks.
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::all()
+            .into_iter()
+            .find(|n| n.discriminant() == value)
+            .ok_or(Error::UnsupportedOrUnknownNetworkID(
+                value as HDPathComponentValue,
+            ))
+    }
+}
+
+impl FromStr for NetworkID {
+    type Err = Error;
+
+    /// Parses either the name of a variant, case-insensitively, e.g.
+    /// `"Stokenet"` or `"mainnet"`, or the decimal discriminant of the network,
+    /// e.g. `"1"` for `Mainnet`, as used by the Radix Gateway and some configs,
+    /// see [`NetworkID::discriminant`].
+    fn from_str(s: &str) -> Result<Self> {
+        let unknown = || Error::UnsupportedOrUnknownNetworkIDFromStr(s.to_owned());
+        if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+            return s
+                .parse::<u8>()
+                .ok()
+                .and_then(|d| Self::try_from(d).ok())
+                .ok_or_else(unknown);
+        }
+        Self::all()
+            .into_iter()
+            .find(|n| n.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(unknown)
+    }
+}
+
+impl TryFrom<&str> for NetworkID {
+    type Error = Error;
+
+    /// Same as `FromStr`, parses a name or a decimal discriminant.
+    fn try_from(value: &str) -> Result<Self> {
+        value.parse()
+    }
+}
+
 impl TryFrom<HDPathComponentValue> for NetworkID {
     type Error = Error;
 
     /// Tries to create a `NetworkID` from a path component, the value
     /// passed MUST be non-hardened / unhardened.
-    /// 
+    ///
+    /// N.B. this is distinct from `TryFrom<u8>` which interprets the raw
+    /// network discriminant.
+    ///
     /// See `unhardened` function.
     fn try_from(value: HDPathComponentValue) -> Result<Self, Self::Error> {
         match value {
@@ -52,7 +103,6 @@ impl TryFrom<HDPathComponentValue> for NetworkID {
 }
 
 impl NetworkID {
-
     /// Returns `<self>H`, that is, the discriminant of the network id
     /// but hardened, as per SLIP10.
     pub fn hardened_hd_component_value(&self) -> HDPathComponentValue {
@@ -62,6 +112,78 @@ impl NetworkID {
         }
     }
 
+    /// Whether this is the Radix mainnet, i.e. if accounts on this network can
+    /// hold real funds.
+    pub fn is_mainnet(&self) -> bool {
+        *self == NetworkID::Mainnet
+    }
+
+    /// Whether this is a testnet, which every network but `Mainnet` is, i.e.
+    /// if accounts on this network only hold test funds.
+    pub fn is_testnet(&self) -> bool {
+        !self.is_mainnet()
+    }
+
+    /// The discriminant of this network, the raw byte identifying the network
+    /// in e.g. transactions, `0x01` for `Mainnet` and `0x02` for `Stokenet`.
+    pub fn discriminant(&self) -> u8 {
+        self.network_definition().id
+    }
+
+    /// The suffix of the human readable part (HRP) of bech32m encoded addresses
+    /// on this network, e.g. `"rdx"` for `Mainnet`, making account addresses
+    /// start with `"account_rdx"`.
+    pub fn hrp_suffix(&self) -> String {
+        self.network_definition().hrp_suffix.into_owned()
+    }
+
+    /// The logical name of this network, as used by the Radix Gateway and the
+    /// babylon-node, e.g. `"mainnet"` or `"stokenet"`.
+    pub fn logical_name(&self) -> String {
+        self.network_definition().logical_name.into_owned()
+    }
+
+    /// The inverse of `logical_name`, case-insensitively, e.g. for reading the
+    /// network from a Radix Gateway response.
+    ///
+    /// Unlike `FromStr`, which matches the names of the variants of this enum,
+    /// this matches the logical names of the networks. Fails for networks this
+    /// software does not support, e.g. the testnet `"enkinet"`.
+    pub fn from_logical_name(logical_name: &str) -> Result<Self> {
+        Self::all()
+            .into_iter()
+            .find(|n| n.logical_name().eq_ignore_ascii_case(logical_name))
+            .ok_or_else(|| Error::UnsupportedOrUnknownNetworkIDFromStr(logical_name.to_owned()))
+    }
+
+    /// Returns the network of the bech32m encoded `address`, e.g. `Mainnet` for
+    /// `"account_rdx1..."`, by validating `address` against each network.
+    ///
+    /// Fails if `address` is not a valid address of any supported network.
+    pub fn parse_from_address(address: impl AsRef<str>) -> Result<Self> {
+        let address = address.as_ref();
+        Self::all()
+            .into_iter()
+            .find(|n| {
+                AddressBech32Decoder::new(&n.network_definition())
+                    .validate_and_decode(address)
+                    .is_ok()
+            })
+            .ok_or_else(|| Error::InvalidAddressOrUnknownNetwork(address.to_owned()))
+    }
+
+    /// Returns the network of the bech32m encoded account `address`, e.g.
+    /// `Stokenet` for `"account_tdx_2_1..."`.
+    ///
+    /// Unlike `parse_from_address` this fails for addresses of other entities
+    /// than accounts, e.g. identities, see [`is_valid_account_address`].
+    pub fn from_address(address: &str) -> Result<Self> {
+        Self::parse_from_address(address)
+            .ok()
+            .filter(|n| is_valid_account_address(address, n))
+            .ok_or_else(|| Error::InvalidAddressOrUnknownNetwork(address.to_owned()))
+    }
+
     /// A network definition used by this library to form bech32 encoded
     /// addresses.
     pub(crate) fn network_definition(&self) -> NetworkDefinition {
@@ -71,3 +193,234 @@ impl NetworkID {
         }
     }
 }
+
+/// Whether `address` is a well-formed bech32m encoded account address on
+/// `network_id`, i.e. if it has the HRP of accounts on `network_id`, a valid
+/// checksum, and the entity byte of an account.
+pub fn is_valid_account_address(address: &str, network_id: &NetworkID) -> bool {
+    AddressBech32Decoder::new(&network_id.network_definition())
+        .validate_and_decode(address)
+        .is_ok_and(|(entity_type, _)| entity_type.is_global_account())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn discriminant_roundtrip() {
+        for network_id in NetworkID::all() {
+            assert_eq!(
+                NetworkID::try_from(network_id.discriminant()),
+                Ok(network_id)
+            );
+        }
+        assert_eq!(NetworkID::Mainnet.discriminant(), 0x01);
+        assert_eq!(NetworkID::Stokenet.discriminant(), 0x02);
+        for network_id in NetworkID::all() {
+            assert_eq!(network_id.clone() as u8, network_id.discriminant());
+        }
+        assert_eq!(NetworkID::try_from(0x02u8), Ok(NetworkID::Stokenet));
+        assert_eq!(
+            NetworkID::try_from(0x21u8),
+            Err(Error::UnsupportedOrUnknownNetworkID(0x21))
+        );
+    }
+
+    #[test]
+    fn hrp_suffix_is_unique_and_matches_discriminant() {
+        let suffixes = NetworkID::all()
+            .iter()
+            .map(NetworkID::hrp_suffix)
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(suffixes.len(), NetworkID::all().len());
+        for network_id in NetworkID::all() {
+            let expected = match network_id {
+                NetworkID::Mainnet => "rdx".to_owned(),
+                // Testnets use `tdx_<discriminant as lowercase hex>_`, see
+                // `NetworkDefinition` in the babylon-node repo.
+                _ => format!("tdx_{:x}_", network_id.discriminant()),
+            };
+            assert_eq!(network_id.hrp_suffix(), expected);
+        }
+    }
+
+    #[test]
+    fn from_str_name_or_discriminant() {
+        assert_eq!(NetworkID::try_from("1"), Ok(NetworkID::Mainnet));
+        assert_eq!(NetworkID::try_from("2"), Ok(NetworkID::Stokenet));
+        assert_eq!(NetworkID::try_from("Stokenet"), Ok(NetworkID::Stokenet));
+        assert_eq!("mainnet".parse::<NetworkID>(), Ok(NetworkID::Mainnet));
+        for network_id in NetworkID::all() {
+            assert_eq!(
+                network_id.discriminant().to_string().parse::<NetworkID>(),
+                Ok(network_id)
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_unknown() {
+        for s in ["999", "34", "0", "", "-1", "nebunet", " 1"] {
+            assert_eq!(
+                NetworkID::try_from(s),
+                Err(Error::UnsupportedOrUnknownNetworkIDFromStr(s.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn is_mainnet_or_testnet() {
+        for network_id in NetworkID::all() {
+            let is_mainnet = match network_id {
+                NetworkID::Mainnet => true,
+                NetworkID::Stokenet => false,
+            };
+            assert_eq!(network_id.is_mainnet(), is_mainnet);
+            assert_eq!(network_id.is_testnet(), !is_mainnet);
+        }
+    }
+
+    #[test]
+    fn logical_name_roundtrip() {
+        for network_id in NetworkID::all() {
+            assert_eq!(
+                NetworkID::from_logical_name(&network_id.logical_name()),
+                Ok(network_id)
+            );
+        }
+    }
+
+    #[test]
+    fn from_logical_name() {
+        assert_eq!(
+            NetworkID::from_logical_name("mainnet"),
+            Ok(NetworkID::Mainnet)
+        );
+        assert_eq!(
+            NetworkID::from_logical_name("Stokenet"),
+            Ok(NetworkID::Stokenet)
+        );
+        assert_eq!(NetworkID::Stokenet.logical_name(), "stokenet");
+    }
+
+    #[test]
+    fn from_logical_name_unsupported_testnet() {
+        // Enkinet is a testnet, but not supported by this software.
+        assert_eq!(
+            NetworkID::from_logical_name("enkinet"),
+            Err(Error::UnsupportedOrUnknownNetworkIDFromStr(
+                "enkinet".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn from_logical_name_unknown() {
+        assert_eq!(
+            NetworkID::from_logical_name("nebunet"),
+            Err(Error::UnsupportedOrUnknownNetworkIDFromStr(
+                "nebunet".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_from_address_invalid() {
+        assert_eq!(
+            NetworkID::parse_from_address("account_rdx1"),
+            Err(Error::InvalidAddressOrUnknownNetwork(
+                "account_rdx1".to_owned()
+            ))
+        );
+    }
+
+    /// Ties together derivation, bech32m encoding and parsing, for every network.
+    #[test]
+    fn hrp_of_derived_address_matches_network() {
+        let expected_hrp_suffix = |network_id: &NetworkID| match network_id {
+            NetworkID::Mainnet => "rdx",
+            NetworkID::Stokenet => "tdx_2_",
+        };
+        let expected_address = |network_id: &NetworkID| match network_id {
+            NetworkID::Mainnet => {
+                "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+            }
+            NetworkID::Stokenet => {
+                "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"
+            }
+        };
+        for network_id in NetworkID::all() {
+            let account = Account::test_0(&network_id, 0);
+            assert_eq!(account.address, expected_address(&network_id));
+
+            let (hrp, _) = account.address.rsplit_once('1').unwrap();
+            assert_eq!(network_id.hrp_suffix(), expected_hrp_suffix(&network_id));
+            assert_eq!(hrp, format!("account_{}", network_id.hrp_suffix()));
+            assert_eq!(
+                NetworkID::parse_from_address(&account.address),
+                Ok(network_id)
+            );
+        }
+    }
+
+    const MAINNET_ACCOUNT: &str =
+        "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4";
+    const STOKENET_ACCOUNT: &str =
+        "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8";
+
+    #[test]
+    fn from_address_mainnet() {
+        assert_eq!(
+            NetworkID::from_address(MAINNET_ACCOUNT),
+            Ok(NetworkID::Mainnet)
+        );
+        assert!(is_valid_account_address(
+            MAINNET_ACCOUNT,
+            &NetworkID::Mainnet
+        ));
+        assert!(!is_valid_account_address(
+            MAINNET_ACCOUNT,
+            &NetworkID::Stokenet
+        ));
+    }
+
+    #[test]
+    fn from_address_stokenet() {
+        assert_eq!(
+            NetworkID::from_address(STOKENET_ACCOUNT),
+            Ok(NetworkID::Stokenet)
+        );
+        assert!(is_valid_account_address(
+            STOKENET_ACCOUNT,
+            &NetworkID::Stokenet
+        ));
+        assert!(!is_valid_account_address(
+            STOKENET_ACCOUNT,
+            &NetworkID::Mainnet
+        ));
+    }
+
+    #[test]
+    fn from_address_malformed() {
+        // Last character changed, invalidating the checksum.
+        let malformed = "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k5";
+        assert_eq!(
+            NetworkID::from_address(malformed),
+            Err(Error::InvalidAddressOrUnknownNetwork(malformed.to_owned()))
+        );
+        assert!(!is_valid_account_address(malformed, &NetworkID::Mainnet));
+        assert!(!is_valid_account_address("", &NetworkID::Mainnet));
+    }
+
+    #[test]
+    fn from_address_identity_is_not_account() {
+        let identity = "identity_rdx12gmv24w02auy87kev4k8uttsdaqrsfpuj8tptzf75m8t4vsk86c6kr";
+        assert!(NetworkID::from_address(identity).is_err());
+        assert!(!is_valid_account_address(identity, &NetworkID::Mainnet));
+        assert_eq!(
+            NetworkID::parse_from_address(identity),
+            Ok(NetworkID::Mainnet)
+        );
+    }
+}
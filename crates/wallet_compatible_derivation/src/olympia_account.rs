@@ -0,0 +1,167 @@
+use crate::prelude::*;
+
+/// A secp256k1 key pair and legacy Radix Olympia mainnet account address, as
+/// derived by the Olympia wallets, useful to recover Olympia era accounts.
+#[derive(ZeroizeOnDrop, Zeroize, derive_more::Display)]
+#[display("{}", self.to_string_include_private_key(false))]
+pub struct OlympiaAccount {
+    /// The secp256k1 private key controlling this account.
+    pub private_key: [u8; 32],
+
+    /// The compressed secp256k1 public key of this account, derived from `private_key`.
+    #[zeroize(skip)]
+    pub public_key: [u8; 33],
+
+    /// A bech32 encoded Radix Olympia mainnet account address, e.g. `rdx1qsp...`.
+    pub address: String,
+
+    /// The value of the last HD path component, the account index.
+    pub index: HDPathComponentValue,
+
+    /// The HD path which was used to derive the keys.
+    pub path: OlympiaAccountPath,
+}
+
+impl OlympiaAccount {
+    pub fn to_string_include_private_key(&self, include_private_key: bool) -> String {
+        let private_key_or_empty = if include_private_key {
+            format!("\nPrivateKey: {}", hex::encode(self.private_key))
+        } else {
+            "".to_owned()
+        };
+        format!(
+            "
+Olympia Address: {}
+Index: {}
+HD Path: {}{}
+PublicKey: {}
+",
+            self.address,
+            self.index,
+            self.path,
+            private_key_or_empty,
+            hex::encode(self.public_key)
+        )
+    }
+
+    /// Derives an [`OlympiaAccount`] using the `mnemonic` and BIP-39 `passphrase`
+    /// (can be the empty string) using the legacy BIP-44 derivation `path`.
+    pub fn derive(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        path: &OlympiaAccountPath,
+    ) -> Self {
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        let (private_key, public_key) =
+            derive_secp256k1_key_pair(seed.as_bytes(), &path.0.derivation_path());
+        drop(seed);
+        Self {
+            private_key,
+            public_key,
+            address: derive_olympia_address(&public_key, &NetworkID::Mainnet),
+            index: path.account_index(),
+            path: path.clone(),
+        }
+    }
+
+    pub fn is_zeroized(&self) -> bool {
+        self.private_key == [0; 32]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// Asserts that deriving with `path` results in the given keys and address.
+    ///
+    /// The vectors below are regression vectors computed by this crate, they
+    /// have not been confirmed against accounts exported from an Olympia wallet.
+    /// The secp256k1 derivation they rely on is checked against the BIP-32 test
+    /// vectors, see `secp256k1_bip32_test_vector_1`, and the addresses are
+    /// encoded as described on `derive_olympia_address`.
+    fn test(
+        mnemonic: Mnemonic24Words,
+        passphrase: &str,
+        path: &str,
+        private_key: &str,
+        public_key: &str,
+        address: &str,
+    ) {
+        let path: OlympiaAccountPath = path.parse().unwrap();
+        let account = OlympiaAccount::derive(&mnemonic, passphrase, &path);
+        assert_eq!(hex::encode(account.private_key), private_key);
+        assert_eq!(hex::encode(account.public_key), public_key);
+        assert_eq!(account.address, address);
+        assert_eq!(account.path, path);
+    }
+
+    #[test]
+    fn derive_olympia_account_mnemonic_0_without_passphrase_index_0() {
+        test(
+            Mnemonic24Words::test_0(),
+            "",
+            "m/44H/1022H/0H/0/0H",
+            "9cc54e13c0de86a6ed252dc24c6c9a8b03dbff0335af9575711b11f33b536ccf",
+            "032f3d6edf4112d3025f3dc911aa453cc2374bfd031f118481d26906f61a149d1e",
+            "rdx1qspj70twmaq395cztu7ujyd2g57vyd6tl5p37yvys8fxjphkrg2f68s2qe95a",
+        );
+    }
+
+    #[test]
+    fn derive_olympia_account_mnemonic_0_without_passphrase_index_1() {
+        test(
+            Mnemonic24Words::test_0(),
+            "",
+            "m/44H/1022H/0H/0/1H",
+            "5044352684f47c3e9cfdb9afd45a8f3a2590a1ed8e4b3d74ac97221b945401d3",
+            "027338bbc2647c3ad5fabc4a5041621597725a47cda82b68218830c4e88d86dc96",
+            "rdx1qsp8xw9mcfj8cwk4l27y55zpvg2ewuj6glx6s2mgyxyrp38g3krde9s43yk9w",
+        );
+    }
+
+    #[test]
+    fn derive_olympia_account_mnemonic_0_with_passphrase_index_0() {
+        test(
+            Mnemonic24Words::test_0(),
+            "radix",
+            "m/44H/1022H/0H/0/0H",
+            "b42e1866c5a5d95be5aeef105e876503dad653ad10fdf59449ad4791bbf49954",
+            "0376137532c3e7b0478b6135b1eff461f00e5c2bc5b5cf8b41274cde17014f830f",
+            "rdx1qsphvym4xtp70vz83dsntv0073slqrju90zmtnutgyn5ehshq98cxrc73pmqu",
+        );
+    }
+
+    #[test]
+    fn derive_olympia_account_mnemonic_1_without_passphrase_index_0() {
+        test(
+            Mnemonic24Words::test_1(),
+            "",
+            "m/44H/1022H/0H/0/0H",
+            "ef4d79ed885a483cc5c2d37b1b97874c24b5e695a901faed069637f43e9a4c72",
+            "030e8d7b739840efc060ca62552db992977ac0f413b8466814bcfcaa4f6ae8f710",
+            "rdx1qspsartmwwvypm7qvr9xy4fdhxffw7kq7sfms3ngzj70e2j0dt50wyq92lftr",
+        );
+    }
+
+    #[test]
+    fn derive_olympia_account_mnemonic_0_without_passphrase_non_hardened_index_0() {
+        test(
+            Mnemonic24Words::test_0(),
+            "",
+            "m/44H/1022H/0H/0/0",
+            "f67a5e6009dbf0387e68b7bf5c271e9b4e2412b179ce43a9164c271cec5d812f",
+            "02f465d7c422b8afa9cc8c3aef48a23ca5797571298e03c98445528d0578fb2c07",
+            "rdx1qsp0gewhcs3t3tafejxr4m6g5g7227t4wy5cuq7fs3z49rg90rajcpcslm63y",
+        );
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut account =
+            OlympiaAccount::derive(&Mnemonic24Words::test_0(), "", &OlympiaAccountPath::new(0));
+        assert!(!account.is_zeroized());
+        account.zeroize();
+        assert!(account.is_zeroized());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::prelude::*;
+
+/// A legacy [BIP-44][bip44] path used by the Radix Olympia wallets to derive
+/// secp256k1 accounts, for example `m/44'/1022'/0'/0/2'`:
+///
+/// ```text
+/// m / 44' / 1022' / 0' / 0 / ACCOUNT_INDEX'
+/// ```
+///
+/// Unlike the Babylon [`AccountPath`] not all levels are hardened, the `change`
+/// level is always the non-hardened `0`. The Olympia desktop wallet and the
+/// Olympia Ledger app hardened the account index, which is what `new` does,
+/// but when parsing a non-hardened account index is accepted as well.
+///
+/// There is no network in the path, and Olympia addresses are formed using
+/// the Olympia mainnet HRP `rdx`, see [`OlympiaAccount`].
+///
+/// [bip44]: https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki
+#[derive(
+    Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, derive_more::Display,
+)]
+pub struct OlympiaAccountPath(pub(crate) BIP32Path<{ Self::DEPTH }>);
+
+impl OlympiaAccountPath {
+    /// The required depth, number of path components/levels of all Olympia account paths.
+    pub const DEPTH: usize = 5;
+
+    /// The `account` level, always `0'` for Olympia.
+    const ACCOUNT: HDPathComponentValue = harden(0);
+
+    /// The non-hardened `change` level, always `0` for Olympia.
+    const CHANGE: HDPathComponentValue = 0;
+
+    /// The last path component, the index of the account.
+    pub(crate) const IDX_ACCOUNT_INDEX: usize = 4;
+
+    /// Creates a new `OlympiaAccountPath` with the hardened account `index`, as
+    /// used by the Olympia wallets.
+    pub fn new(index: EntityIndex) -> Self {
+        BIP32Path::<{ Self::DEPTH }>([
+            PURPOSE,
+            COINTYPE,
+            Self::ACCOUNT,
+            Self::CHANGE,
+            harden(index),
+        ])
+        .try_into()
+        .expect("Should have constructed a valid OlympiaAccountPath from index.")
+    }
+
+    /// Read the accounts `index` of this path, unhardened if it was hardened.
+    pub fn account_index(&self) -> HDPathComponentValue {
+        let index = self.0.clone().components()[Self::IDX_ACCOUNT_INDEX];
+        if is_hardened(index) {
+            unhardened(index)
+        } else {
+            index
+        }
+    }
+}
+
+impl TryFrom<BIP32Path<{ Self::DEPTH }>> for OlympiaAccountPath {
+    type Error = crate::Error;
+
+    /// Tries to create a new `OlympiaAccountPath` from a `BIP32Path`, by
+    /// validating it, returning `Err` if it is invalid.
+    fn try_from(value: BIP32Path<{ Self::DEPTH }>) -> Result<Self, Self::Error> {
+        let expected_prefix = [PURPOSE, COINTYPE, Self::ACCOUNT, Self::CHANGE];
+        if value.clone().components()[..Self::IDX_ACCOUNT_INDEX] != expected_prefix {
+            return Err(Error::InvalidOlympiaAccountPath(value.to_string()));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl FromStr for OlympiaAccountPath {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<BIP32Path<{ Self::DEPTH }>>()
+            .and_then(|p| p.try_into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        let path = OlympiaAccountPath::new(7);
+        assert_eq!(path.to_string(), "m/44H/1022H/0H/0/7H");
+        assert_eq!(path.account_index(), 7);
+    }
+
+    #[test]
+    fn parse_hardened_and_non_hardened_index() {
+        let hardened: OlympiaAccountPath = "m/44'/1022'/0'/0/3'".parse().unwrap();
+        assert_eq!(hardened, OlympiaAccountPath::new(3));
+        let non_hardened: OlympiaAccountPath = "m/44'/1022'/0'/0/3".parse().unwrap();
+        assert_eq!(non_hardened.to_string(), "m/44H/1022H/0H/0/3");
+        assert_eq!(non_hardened.account_index(), 3);
+    }
+
+    #[test]
+    fn hardened_change_is_invalid() {
+        assert_eq!(
+            "m/44H/1022H/0H/0H/0H".parse::<OlympiaAccountPath>(),
+            Err(Error::InvalidOlympiaAccountPath(
+                "m/44H/1022H/0H/0H/0H".to_owned()
+            ))
+        );
+    }
+
+    #[test]
+    fn babylon_path_is_invalid() {
+        assert_eq!(
+            "m/44H/1022H/1H/525H/1460H/0H".parse::<OlympiaAccountPath>(),
+            Err(Error::InvalidDepthOfBIP32Path {
+                expected: 5,
+                found: 6
+            })
+        );
+    }
+}
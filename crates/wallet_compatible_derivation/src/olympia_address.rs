@@ -0,0 +1,120 @@
+use crate::prelude::*;
+
+use bech32::{FromBase32, ToBase32, Variant};
+
+/// The byte prefixed to the compressed public key of Olympia account addresses,
+/// identifying the address as an account address.
+const OLYMPIA_ACCOUNT_ADDRESS_PREFIX: u8 = 0x04;
+
+/// The bech32 human readable part of Olympia account addresses on `network_id`,
+/// `"rdx"` on mainnet and `"tdx"` on Stokenet.
+pub fn olympia_hrp(network_id: &NetworkID) -> &'static str {
+    match network_id {
+        NetworkID::Mainnet => "rdx",
+        NetworkID::Stokenet => "tdx",
+    }
+}
+
+/// The legacy Radix Olympia account address of a compressed secp256k1
+/// `public_key` on `network_id`, e.g. `rdx1qsp...` on mainnet.
+///
+/// Olympia account addresses are the bech32 - not bech32m, as Babylon addresses
+/// are - encoding of the byte `0x04` followed by the 33 bytes compressed public
+/// key, with the HRP of the network, see [`olympia_hrp`]. Unlike Babylon addresses
+/// the public key is not hashed, thus the address can be decoded into the key.
+pub fn derive_olympia_address(public_key: &[u8; 33], network_id: &NetworkID) -> String {
+    let mut data = vec![OLYMPIA_ACCOUNT_ADDRESS_PREFIX];
+    data.extend_from_slice(public_key);
+    bech32::encode(olympia_hrp(network_id), data.to_base32(), Variant::Bech32)
+        .expect("Should always be able to bech32 encode an Olympia address.")
+}
+
+/// Whether `address` is a well-formed Olympia account address on `network_id`,
+/// i.e. if it has the HRP of `network_id`, a valid bech32 checksum, the account
+/// address prefix, and a valid compressed secp256k1 public key.
+pub fn is_valid_olympia_address(address: &str, network_id: &NetworkID) -> bool {
+    olympia_address_public_key(address, network_id).is_some()
+}
+
+/// Decodes the compressed secp256k1 public key of the Olympia account `address`
+/// on `network_id`, or `None` if `address` is not valid, see
+/// [`is_valid_olympia_address`].
+pub fn olympia_address_public_key(address: &str, network_id: &NetworkID) -> Option<[u8; 33]> {
+    let (hrp, data, variant) = bech32::decode(address).ok()?;
+    if hrp != olympia_hrp(network_id) || variant != Variant::Bech32 {
+        return None;
+    }
+    let bytes = Vec::<u8>::from_base32(&data).ok()?;
+    let (&prefix, public_key) = bytes.split_first()?;
+    if prefix != OLYMPIA_ACCOUNT_ADDRESS_PREFIX {
+        return None;
+    }
+    bip32::secp256k1::PublicKey::from_sec1_bytes(public_key).ok()?;
+    public_key.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use bech32::{ToBase32, Variant};
+
+    /// The public key and address of the Olympia account of `test_0` at index
+    /// `0`, derived by this crate, see `OlympiaAccount::derive`.
+    const PUBLIC_KEY: &str = "032f3d6edf4112d3025f3dc911aa453cc2374bfd031f118481d26906f61a149d1e";
+    const ADDRESS: &str = "rdx1qspj70twmaq395cztu7ujyd2g57vyd6tl5p37yvys8fxjphkrg2f68s2qe95a";
+
+    fn public_key() -> [u8; 33] {
+        hex::decode(PUBLIC_KEY).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn mainnet_vector() {
+        assert_eq!(
+            derive_olympia_address(&public_key(), &NetworkID::Mainnet),
+            ADDRESS
+        );
+        assert!(is_valid_olympia_address(ADDRESS, &NetworkID::Mainnet));
+        assert_eq!(
+            olympia_address_public_key(ADDRESS, &NetworkID::Mainnet),
+            Some(public_key())
+        );
+    }
+
+    #[test]
+    fn stokenet_roundtrip() {
+        let address = derive_olympia_address(&public_key(), &NetworkID::Stokenet);
+        assert!(address.starts_with("tdx1qsp"));
+        assert!(is_valid_olympia_address(&address, &NetworkID::Stokenet));
+        assert!(!is_valid_olympia_address(&address, &NetworkID::Mainnet));
+        assert!(!is_valid_olympia_address(ADDRESS, &NetworkID::Stokenet));
+    }
+
+    #[test]
+    fn invalid() {
+        let wrong_checksum = ADDRESS.replace("e95a", "e95b");
+        let babylon = "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4";
+        let bech32m = {
+            let mut data = vec![0x04];
+            data.extend_from_slice(&public_key());
+            bech32::encode("rdx", data.to_base32(), Variant::Bech32m).unwrap()
+        };
+        let not_on_curve = {
+            let mut data = vec![0x04, 0x02];
+            data.extend_from_slice(&[0xff; 32]);
+            bech32::encode("rdx", data.to_base32(), Variant::Bech32).unwrap()
+        };
+        for address in [
+            "",
+            "rdx1",
+            &wrong_checksum,
+            babylon,
+            &bech32m,
+            &not_on_curve,
+        ] {
+            assert!(
+                !is_valid_olympia_address(address, &NetworkID::Mainnet),
+                "{address}"
+            );
+        }
+    }
+}
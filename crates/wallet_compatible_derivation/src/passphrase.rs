@@ -0,0 +1,116 @@
+use crate::prelude::*;
+use zeroize::Zeroizing;
+
+/// An optional BIP-39 passphrase, often referred to as "the 25th word", which is
+/// zeroized when dropped.
+///
+/// All public derive functions, e.g. [`Account::derive`], take an
+/// `impl Into<Passphrase>`, so a `&Passphrase`, a `String` or a `&str` can be
+/// passed, the latter being copied into a zeroized buffer. The empty passphrase
+/// is the `Default`, and is what you want to use to be compatible with the
+/// Radix Wallet.
+#[derive(Clone, PartialEq, Eq, Default, Zeroize, ZeroizeOnDrop)]
+pub struct Passphrase(Zeroizing<String>);
+
+impl Passphrase {
+    /// Returns `true` if this is the empty passphrase.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Passphrase(***redacted***)")
+    }
+}
+
+impl From<String> for Passphrase {
+    /// Takes ownership of `value`, without copying it.
+    fn from(value: String) -> Self {
+        Self(Zeroizing::new(value))
+    }
+}
+
+impl From<&str> for Passphrase {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_owned())
+    }
+}
+
+impl From<&String> for Passphrase {
+    fn from(value: &String) -> Self {
+        Self::from(value.as_str())
+    }
+}
+
+impl From<&Passphrase> for Passphrase {
+    fn from(value: &Passphrase) -> Self {
+        value.clone()
+    }
+}
+
+impl FromStr for Passphrase {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl AsRef<str> for Passphrase {
+    fn as_ref(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn default_is_empty() {
+        assert!(Passphrase::default().is_empty());
+        assert_eq!(Passphrase::default().as_ref(), "");
+    }
+
+    #[test]
+    fn from_str() {
+        let sut: Passphrase = "radix".parse().unwrap();
+        assert!(!sut.is_empty());
+        assert_eq!(sut.as_ref(), "radix");
+    }
+
+    #[test]
+    fn from_str_string_and_ref_are_equal() {
+        let sut: Passphrase = "radix".parse().unwrap();
+        assert_eq!(Passphrase::from("radix"), sut);
+        assert_eq!(Passphrase::from("radix".to_owned()), sut);
+        assert_eq!(Passphrase::from(&"radix".to_owned()), sut);
+        assert_eq!(Passphrase::from(&sut), sut);
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        let sut: Passphrase = "radix".parse().unwrap();
+        assert_eq!(format!("{:?}", sut), "Passphrase(***redacted***)");
+    }
+
+    #[test]
+    fn derive_with_passphrase_same_as_str() {
+        let path = AccountPath::new(&NetworkID::Mainnet, 0);
+        let passphrase: Passphrase = "radix".parse().unwrap();
+        let account = Account::derive_unwrap(&Mnemonic24Words::test_0(), &passphrase, &path);
+        assert_eq!(
+            account.address,
+            Account::derive_unwrap(&Mnemonic24Words::test_0(), "radix", &path).address
+        );
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut sut: Passphrase = "radix".parse().unwrap();
+        sut.zeroize();
+        assert!(sut.is_empty());
+    }
+}
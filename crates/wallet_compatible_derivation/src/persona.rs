@@ -0,0 +1,196 @@
+use crate::prelude::*;
+
+use ed25519_dalek::{PublicKey, SecretKey};
+
+/// A tuple of keys and Radix Babylon Identity address, for a virtual
+/// identity, which is what a Persona in the Radix Wallet is.
+#[derive(ZeroizeOnDrop, Zeroize, derive_more::Display)]
+#[display("{}", self.to_string_include_private_key(false))]
+pub struct Persona {
+    /// The network used to derive the `address`.
+    #[zeroize(skip)]
+    pub network_id: NetworkID,
+
+    /// The private key controlling this identity.
+    pub private_key: SecretKey,
+
+    /// The public key of this identity, derived from `private_key`, was used
+    /// together with the `network_id` to derive the `address`.
+    #[zeroize(skip)]
+    pub public_key: PublicKey,
+
+    /// A bech32 encoded Radix Babylon identity address, e.g. `identity_rdx1...`.
+    pub address: String,
+
+    /// The value of the last HD path component, the identity index.
+    pub index: HDPathComponentValue,
+
+    /// The HD path which was used to derive the keys.
+    pub path: IdentityPath,
+
+    /// ID used to identify that two personas have been derived from the same mnemonic - does not reveal any secrets.
+    pub factor_source_id: FactorSourceID,
+}
+
+impl std::fmt::Debug for Persona {
+    /// Redacts the private key, all other fields are public information.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Persona")
+            .field("network_id", &self.network_id)
+            .field("private_key", &format_args!("***redacted***"))
+            .field("public_key", &format_args!("{}", self.public_key.to_hex()))
+            .field("address", &self.address)
+            .field("index", &self.index)
+            .field("path", &self.path)
+            .field("factor_source_id", &self.factor_source_id)
+            .finish()
+    }
+}
+
+impl Persona {
+    pub fn to_string_include_private_key(&self, include_private_key: bool) -> String {
+        let private_key_or_empty = if include_private_key {
+            format!("\nPrivateKey: {}", self.private_key.to_hex())
+        } else {
+            "".to_owned()
+        };
+        format!(
+            "
+Factor Source ID: {}
+Identity Address: {}
+Network: {}
+Index: {}
+HD Path: {}{}
+PublicKey: {}
+",
+            self.factor_source_id,
+            self.address,
+            self.network_id,
+            self.index,
+            self.path,
+            private_key_or_empty,
+            self.public_key.to_hex()
+        )
+    }
+
+    /// Derives a [`Persona`] using the `mnemonic` and BIP-39 `passphrase` (can be the empty string) using the hierarchical deterministic derivation path `path`.
+    ///
+    /// See [`Persona`] for more details, but in short it is an Identity Address + key pair.
+    ///
+    /// Returns `Err` if key derivation or address encoding fails, which never
+    /// happens for a valid `path`.
+    pub fn derive(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        path: &IdentityPath,
+    ) -> Result<Self> {
+        Self::derive_from_seed(&Seed::from_mnemonic(mnemonic, passphrase), path)
+    }
+
+    /// Derives a [`Persona`] from the BIP-39 `seed` using the hierarchical
+    /// deterministic derivation path `path`, without recomputing the seed.
+    pub fn derive_from_seed(seed: &Seed, path: &IdentityPath) -> Result<Self> {
+        Self::derive_from_seed_with_id(seed, &FactorSourceID::from_seed(seed), path)
+    }
+
+    /// Like `derive_from_seed` with the `FactorSourceID` of `seed` computed by the
+    /// caller, see `Account::derive_from_seed_with_id`.
+    pub(crate) fn derive_from_seed_with_id(
+        seed: &Seed,
+        factor_source_id: &FactorSourceID,
+        path: &IdentityPath,
+    ) -> Result<Self> {
+        let network_id = path.network_id();
+        let (private_key, public_key) = derive_ed25519_key_pair(seed.as_bytes(), &path.0.inner())?;
+        let address = derive_identity_address(&public_key, &network_id)?;
+
+        Ok(Self {
+            network_id,
+            private_key,
+            public_key,
+            address,
+            index: path.identity_index(),
+            path: path.clone(),
+            factor_source_id: factor_source_id.clone(),
+        })
+    }
+
+    pub fn is_zeroized(&self) -> bool {
+        self.private_key.to_bytes() == [0; 32]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn test(
+        mnemonic: Mnemonic24Words,
+        passphrase: &str,
+        network_id: NetworkID,
+        index: HDPathComponentValue,
+        private_key: &str,
+        public_key: &str,
+        address: &str,
+    ) {
+        let path = IdentityPath::new(&network_id, index);
+        let persona = Persona::derive(&mnemonic, passphrase, &path).unwrap();
+        assert_eq!(persona.private_key.to_hex(), private_key);
+        assert_eq!(persona.public_key.to_hex(), public_key);
+        assert_eq!(persona.address, address);
+        assert_eq!(persona.index, index);
+        assert_eq!(persona.path, path);
+    }
+
+    #[test]
+    fn derive_persona_mnemonic_0_without_passphrase_mainnet_index_0() {
+        test(
+            Mnemonic24Words::test_0(),
+            "",
+            NetworkID::Mainnet,
+            0,
+            "c26ae8b9675fcc494621147f38e470aa67fd53c067d9cab431d16ab7623d8273",
+            "474b4da62c55032fdf6a5a2d84a73eeffc780513c1f17d2cedc09f5e980efbfd",
+            "identity_rdx12gmv24w02auy87kev4k8uttsdaqrsfpuj8tptzf75m8t4vsk86c6kr",
+        );
+    }
+
+    #[test]
+    fn derive_persona_mnemonic_0_without_passphrase_mainnet_index_1() {
+        test(
+            Mnemonic24Words::test_0(),
+            "",
+            NetworkID::Mainnet,
+            1,
+            "84edb0bf095919b2ac27a86a72044264c9e717b029b733a10addd35e81cb80c5",
+            "317141fe12eb13250dc095b49fdc7ba544eab018a03d1a61b90494ac3bba4398",
+            "identity_rdx12fvknhjau20pxmampj3vfnem3eddg0kff40nh4enpkqr2hhlzf8dfd",
+        );
+    }
+
+    #[test]
+    fn derive_persona_mnemonic_0_without_passphrase_stokenet_index_0() {
+        test(
+            Mnemonic24Words::test_0(),
+            "",
+            NetworkID::Stokenet,
+            0,
+            "7ec9fb9fa31c8d2b27aba5d50a8a5a17e22a7f0c647f180bb03b33b166885115",
+            "388ec7c0b51616f1ab061032229020fba48a1a5f16f85ff359afe0e8940dd7df",
+            "identity_tdx_2_1225v5h7le0rtm08ysd9kh9t9fqky6d0t8hvw776t2znlvpdccckwmc",
+        );
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut persona = Persona::derive(
+            &Mnemonic24Words::test_0(),
+            "",
+            &IdentityPath::new(&NetworkID::Mainnet, 0),
+        )
+        .unwrap();
+        assert!(!persona.is_zeroized());
+        persona.zeroize();
+        assert!(persona.is_zeroized());
+    }
+}
@@ -0,0 +1,201 @@
+use crate::prelude::*;
+
+/// A Radix Babylon [BIP-32][bip32] path used to derive keys for securified
+/// accounts, i.e. accounts controlled by an access controller with multiple
+/// factors (MFA), e.g. `m/44'/1022'/1'/525'/1460'/1073741824'`.
+///
+/// Identical to [`AccountPath`] except that the account index must be in the
+/// upper half of the hardened keyspace, `2^30..2^31`, which Radix wallets use
+/// for securified entities, Sargon formats that index with the `S` suffix, e.g.
+/// `0S` for `1073741824H`, see `to_sargon_string`:
+///
+/// ```text
+/// m / 44' / 1022' / NETWORK_ID' / 525' / KEY_KIND' / (2^30 + SECURIFIED_INDEX)'
+/// ```
+///
+/// The keys are derived exactly like the keys of an `AccountPath` with the same
+/// components, see [`Account::derive_securified`].
+///
+/// [bip32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+#[derive(
+    Zeroize, ZeroizeOnDrop, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, derive_more::Display,
+)]
+pub struct SecurifiedAccountPath(pub(crate) BIP32Path<{ AccountPath::DEPTH }>);
+
+impl SecurifiedAccountPath {
+    /// Creates a new `SecurifiedAccountPath` given the tuple (network, key kind,
+    /// securified index), where the account index of the path is
+    /// `2^30 + securified_index`.
+    ///
+    /// Returns `Err` if `securified_index` is not less than `2^30`.
+    pub fn new(
+        network_id: &NetworkID,
+        key_kind: KeyKind,
+        securified_index: EntityIndex,
+    ) -> Result<Self> {
+        if securified_index >= SECURIFIED_OFFSET {
+            return Err(Error::InvalidSecurifiedIndex(securified_index));
+        }
+        AccountPath::new_with_key_kind(network_id, key_kind, SECURIFIED_OFFSET + securified_index)
+            .try_into()
+    }
+
+    /// Read the `network_id` of this path.
+    pub fn network_id(&self) -> NetworkID {
+        AccountPath::from(self.clone()).network_id()
+    }
+
+    /// Read the `key_kind` of this path.
+    pub fn key_kind(&self) -> KeyKind {
+        AccountPath::from(self.clone()).key_kind()
+    }
+
+    /// Read the account index of this path, i.e. `2^30 + securified_index`.
+    pub fn account_index(&self) -> HDPathComponentValue {
+        AccountPath::from(self.clone()).account_index()
+    }
+
+    /// Read the securified index of this path, i.e. the account index minus
+    /// `2^30`, e.g. `0` for `1073741824H`.
+    pub fn securified_index(&self) -> EntityIndex {
+        self.account_index() - SECURIFIED_OFFSET
+    }
+
+    /// Formats the path with the account index in Sargon's securified
+    /// notation, e.g. `"m/44H/1022H/1H/525H/1460H/0S"`.
+    pub fn to_sargon_string(&self) -> String {
+        self.0.to_sargon_string()
+    }
+}
+
+impl TryFrom<AccountPath> for SecurifiedAccountPath {
+    type Error = crate::Error;
+
+    /// Fails if the account index of `value` is not in the securified half
+    /// of the hardened keyspace.
+    fn try_from(value: AccountPath) -> Result<Self, Self::Error> {
+        let index = value.account_index();
+        if index < SECURIFIED_OFFSET {
+            return Err(Error::InvalidSecurifiedIndex(index));
+        }
+        Ok(Self(value.0.clone()))
+    }
+}
+
+impl From<SecurifiedAccountPath> for AccountPath {
+    fn from(value: SecurifiedAccountPath) -> Self {
+        Self(value.0.clone())
+    }
+}
+
+impl TryFrom<BIP32Path<{ AccountPath::DEPTH }>> for SecurifiedAccountPath {
+    type Error = crate::Error;
+
+    /// Tries to create a new `SecurifiedAccountPath` from a `BIP32Path`, by
+    /// validating it, returning `Err` if it is invalid.
+    fn try_from(value: BIP32Path<{ AccountPath::DEPTH }>) -> Result<Self, Self::Error> {
+        AccountPath::try_from(value).and_then(Self::try_from)
+    }
+}
+
+impl FromStr for SecurifiedAccountPath {
+    type Err = crate::Error;
+
+    /// Accepts Sargon's securified notation too, e.g. `"m/44H/1022H/1H/525H/1460H/0S"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<AccountPath>().and_then(Self::try_from)
+    }
+}
+
+impl Account {
+    /// Derives the key pair, and address, of a securified account at `path`,
+    /// using the `mnemonic` and BIP-39 `passphrase` (can be the empty string).
+    ///
+    /// Derives exactly the same keys as [`Account::derive`] with the
+    /// `AccountPath` with the same components.
+    pub fn derive_securified(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        path: &SecurifiedAccountPath,
+    ) -> Result<Self> {
+        Self::derive(mnemonic, passphrase, &AccountPath::from(path.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn new() {
+        let path = SecurifiedAccountPath::new(&NetworkID::Stokenet, KeyKind::TransactionSigning, 1)
+            .unwrap();
+        assert_eq!(path.to_string(), "m/44H/1022H/2H/525H/1460H/1073741825H");
+        assert_eq!(path.to_sargon_string(), "m/44H/1022H/2H/525H/1460H/1S");
+        assert_eq!(path.network_id(), NetworkID::Stokenet);
+        assert_eq!(path.key_kind(), KeyKind::TransactionSigning);
+        assert_eq!(path.account_index(), 2u32.pow(30) + 1);
+        assert_eq!(path.securified_index(), 1);
+        assert_eq!(path, "m/44H/1022H/2H/525H/1460H/1S".parse().unwrap());
+    }
+
+    #[test]
+    fn new_index_too_large() {
+        assert_eq!(
+            SecurifiedAccountPath::new(&NetworkID::Mainnet, KeyKind::TransactionSigning, 1 << 30),
+            Err(Error::InvalidSecurifiedIndex(1 << 30))
+        );
+    }
+
+    #[test]
+    fn unsecurified_index_is_err() {
+        assert_eq!(
+            "m/44H/1022H/1H/525H/1460H/1073741823H".parse::<SecurifiedAccountPath>(),
+            Err(Error::InvalidSecurifiedIndex(2u32.pow(30) - 1))
+        );
+        assert_eq!(
+            SecurifiedAccountPath::try_from(AccountPath::new(&NetworkID::Mainnet, 0)),
+            Err(Error::InvalidSecurifiedIndex(0))
+        );
+        assert!("m/44H/1022H/1H/618H/1460H/0S"
+            .parse::<SecurifiedAccountPath>()
+            .is_err());
+    }
+
+    /// Cross references the securified test vectors in `account.rs`.
+    #[test]
+    fn derive_securified_eq_securified_vectors() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_2());
+        [
+            (
+                0,
+                "b0b9180f7c96778cffba7af2ef1ddf4705fca21b965e8a722ccf2ec403c35950",
+                "account_rdx128znphf3gxek50qyxjcuels6xtulum3g46vhr43ryavj7zr53xxded",
+            ),
+            (
+                1,
+                "c1880587c727f2f01dfdf61d19b44283d311b31c12e8898b774b73e8067d25b1",
+                "account_rdx12y8gd9dyz9mhg3jv5p9md5gvuzc34m0p90te0hx7aqgsvuy5g2p09s",
+            ),
+            (
+                2,
+                "837bc77bb29e4702be39c69fbade7d350bc23f6daddf68a64474984e899a97a3",
+                "account_rdx12xluhgaw3vcyskpsmswu279jlysmrdjuk23erjcx8s83kcgx3r4zvn",
+            ),
+        ]
+        .into_iter()
+        .for_each(|(securified_index, private_key, address)| {
+            let path = SecurifiedAccountPath::new(
+                &NetworkID::Mainnet,
+                KeyKind::TransactionSigning,
+                securified_index,
+            )
+            .unwrap();
+            let account = Account::derive_securified(&mnemonic, "", &path).unwrap();
+            assert_eq!(account.private_key.to_hex(), private_key);
+            assert_eq!(account.address, address);
+            assert_eq!(account.index, 2u32.pow(30) + securified_index);
+            assert_eq!(account.path, AccountPath::from(path));
+        });
+    }
+}
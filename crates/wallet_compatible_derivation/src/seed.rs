@@ -0,0 +1,97 @@
+use crate::prelude::*;
+
+/// The 64 bytes BIP-39 seed of a mnemonic and BIP-39 passphrase, from which all
+/// accounts are derived.
+///
+/// Computing the seed runs PBKDF2-HMAC-SHA512 with 2048 rounds, so when deriving
+/// many accounts, create the `Seed` once and use `Account::derive_from_seed`.
+///
+/// As sensitive as the mnemonic itself, and zeroized when dropped.
+///
+/// If only the seed is known, not the mnemonic, e.g. when extracted from the
+/// backup of another wallet, use `Seed::from_hex`. The `FactorSourceID` can
+/// still be computed from it, see `FactorSourceID::from_seed`.
+#[derive(Zeroize, ZeroizeOnDrop, Clone, PartialEq, Eq)]
+pub struct Seed([u8; 64]);
+
+impl std::fmt::Debug for Seed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Seed(***redacted***)")
+    }
+}
+
+impl Seed {
+    /// Computes the BIP-39 seed of the `mnemonic` and BIP-39 `passphrase` (can be
+    /// the empty string).
+    pub fn from_mnemonic(mnemonic: &Mnemonic, passphrase: impl Into<Passphrase>) -> Self {
+        Self(mnemonic.to_seed(passphrase.into()))
+    }
+
+    /// Decodes a seed from the hex of its 64 bytes, decoding directly into the
+    /// zeroized buffer of the seed, returning `Err` if `seed_hex` is not exactly
+    /// 128 hex characters.
+    pub fn from_hex(seed_hex: &str) -> Result<Self> {
+        let mut seed = Self([0; 64]);
+        hex::decode_to_slice(seed_hex, &mut seed.0).map_err(|_| Error::InvalidSeedHex)?;
+        Ok(seed)
+    }
+
+    /// The 64 bytes of this seed.
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.0
+    }
+
+    pub fn is_zeroized(&self) -> bool {
+        self.0 == [0; 64]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn debug_is_redacted() {
+        let seed = Seed::from_mnemonic(&Mnemonic24Words::test_0(), "");
+        assert_eq!(format!("{seed:?}"), "Seed(***redacted***)");
+    }
+
+    #[test]
+    fn from_mnemonic_eq_to_seed() {
+        let mnemonic = Mnemonic24Words::test_0();
+        let sut = Seed::from_mnemonic(&mnemonic, "radix");
+        assert_eq!(sut.as_bytes(), &mnemonic.to_seed("radix"));
+        assert_ne!(sut, Seed::from_mnemonic(&mnemonic, ""));
+    }
+
+    #[test]
+    fn from_hex() {
+        let seed = Seed::from_mnemonic(&Mnemonic24Words::test_0(), "radix");
+        let seed_hex = hex::encode(seed.as_bytes());
+        assert_eq!(Seed::from_hex(&seed_hex).unwrap(), seed);
+        assert_eq!(Seed::from_hex(&seed_hex.to_uppercase()).unwrap(), seed);
+    }
+
+    #[test]
+    fn from_hex_invalid() {
+        let seed_hex = hex::encode([0xab; 64]);
+        assert_eq!(Seed::from_hex(&seed_hex[..126]), Err(Error::InvalidSeedHex));
+        assert_eq!(
+            Seed::from_hex(&format!("{seed_hex}ab")),
+            Err(Error::InvalidSeedHex)
+        );
+        assert_eq!(
+            Seed::from_hex(&seed_hex.replacen("ab", "zz", 1)),
+            Err(Error::InvalidSeedHex)
+        );
+        assert_eq!(Seed::from_hex(""), Err(Error::InvalidSeedHex));
+    }
+
+    #[test]
+    fn zeroize() {
+        let mut sut = Seed::from_mnemonic(&Mnemonic24Words::test_1(), "");
+        assert!(!sut.is_zeroized());
+        sut.zeroize();
+        assert!(sut.is_zeroized());
+    }
+}
@@ -0,0 +1,76 @@
+use ed25519_dalek::{PublicKey, Signature};
+
+/// Verifies many `(public_key, message, signature)` tuples at once, e.g. signatures
+/// produced by derived accounts, returning if each signature is valid or not, in
+/// the same order as `items`.
+///
+/// Uses strict verification, like `Account::verify`, rejecting small order
+/// public keys and signatures. Thus Ed25519 batch verification is not used,
+/// since it accepts some signatures which strict verification rejects.
+pub fn verify_batch(items: &[(PublicKey, &[u8], Signature)]) -> Vec<bool> {
+    items
+        .iter()
+        .map(|(k, m, s)| k.verify_strict(m, s).is_ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use ed25519_dalek::ExpandedSecretKey;
+
+    fn signed(account: &Account, message: &'static [u8]) -> (PublicKey, &'static [u8], Signature) {
+        let signature =
+            ExpandedSecretKey::from(&account.private_key).sign(message, &account.public_key);
+        (account.public_key, message, signature)
+    }
+
+    #[test]
+    fn all_valid() {
+        let accounts =
+            Account::derive_range(&Mnemonic24Words::test_0(), "", &NetworkID::Mainnet, 0..3)
+                .unwrap();
+        let items = accounts
+            .iter()
+            .map(|a| signed(a, b"Hello Radix"))
+            .collect::<Vec<_>>();
+        assert_eq!(verify_batch(&items), vec![true, true, true]);
+    }
+
+    #[test]
+    fn mix_of_valid_and_tampered() {
+        let accounts =
+            Account::derive_range(&Mnemonic24Words::test_1(), "", &NetworkID::Stokenet, 0..4)
+                .unwrap();
+        let mut items = accounts
+            .iter()
+            .map(|a| signed(a, b"Hello Radix"))
+            .collect::<Vec<_>>();
+        // Tampered message
+        items[1].1 = b"Hello Radix!";
+        // Signature by another account
+        items[3].2 = items[0].2;
+        assert_eq!(verify_batch(&items), vec![true, false, true, false]);
+    }
+
+    #[test]
+    fn small_order_public_key_is_invalid() {
+        // The identity point as public key, and as `R` with `s = 0`, which
+        // satisfies the non-strict verification equation for any message.
+        let mut identity = [0u8; 64];
+        identity[0] = 1;
+        let public_key = PublicKey::from_bytes(&identity[..32]).unwrap();
+        let signature = Signature::from_bytes(&identity).unwrap();
+        let message: &[u8] = b"Hello Radix";
+        assert!(ed25519_dalek::Verifier::verify(&public_key, message, &signature).is_ok());
+        assert_eq!(
+            verify_batch(&[(public_key, message, signature)]),
+            vec![false]
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(verify_batch(&[]), Vec::<bool>::new());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::prelude::*;
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+/// Derives the account at `index` on the network with discriminant `network_id`,
+/// e.g. `1` for Mainnet, using the `mnemonic` and BIP-39 `passphrase` (can be
+/// the empty string), for use from JavaScript. The shorthands of the test
+/// mnemonics, e.g. `"__test_0"`, are not accepted.
+///
+/// Returns a plain object with the keys `address`, `public_key`, `path`,
+/// `index`, `network_id` and `factor_source_id`, and `private_key` only if
+/// `include_private_key` is `true`.
+#[wasm_bindgen]
+pub fn derive_account(
+    mnemonic: &str,
+    passphrase: &str,
+    network_id: u8,
+    index: u32,
+    include_private_key: bool,
+) -> Result<JsValue, JsError> {
+    let mnemonic = Mnemonic::parse_phrase(mnemonic)?;
+    let network_id = NetworkID::try_from(network_id)?;
+    if is_hardened(index) {
+        return Err(JsError::new(&format!(
+            "Invalid account index: '{index}', must be less than 2^31"
+        )));
+    }
+    let account = Account::derive(&mnemonic, passphrase, &AccountPath::new(&network_id, index))?;
+
+    let object = Object::new();
+    let set = |key: &str, value: JsValue| {
+        Reflect::set(&object, &key.into(), &value)
+            .map(drop)
+            .map_err(|_| JsError::new(&format!("Failed to set '{key}' of account")))
+    };
+    set("address", account.address.as_str().into())?;
+    set("public_key", account.public_key.to_hex().into())?;
+    set("path", account.path.to_string().into())?;
+    set("index", account.index.into())?;
+    set("network_id", account.network_id.discriminant().into())?;
+    set("factor_source_id", account.factor_source_id.to_hex().into())?;
+    if include_private_key {
+        set("private_key", account.private_key.to_hex().into())?;
+    }
+    Ok(object.into())
+}
+
+/// Derivation never needs any randomness, but `getrandom` is a transitive
+/// dependency, which requires a backend on `wasm32-unknown-unknown`. This one
+/// always fails, so that no JavaScript glue for randomness is bundled.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn unsupported_getrandom(_: &mut [u8]) -> Result<(), getrandom::Error> {
+    Err(getrandom::Error::UNSUPPORTED)
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+getrandom::register_custom_getrandom!(unsupported_getrandom);
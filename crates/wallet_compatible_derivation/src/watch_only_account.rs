@@ -0,0 +1,111 @@
+use crate::prelude::*;
+
+use ed25519_dalek::PublicKey;
+
+/// The public key and Radix Babylon account address of an account, without
+/// its private key, e.g. for auditing which accounts a mnemonic controls.
+///
+/// See [`Account::derive_public_only`].
+#[derive(ZeroizeOnDrop, Zeroize, Debug, derive_more::Display)]
+#[display(
+    "
+Factor Source ID: {}
+Address: {}
+Network: {}
+Index: {}
+HD Path: {}
+PublicKey: {}
+",
+    factor_source_id,
+    address,
+    network_id,
+    index,
+    path,
+    public_key.to_hex()
+)]
+pub struct WatchOnlyAccount {
+    /// The network used to derive the `address`.
+    #[zeroize(skip)]
+    pub network_id: NetworkID,
+
+    /// The public key of this account, which was used together with the
+    /// `network_id` to derive the `address`.
+    #[zeroize(skip)]
+    pub public_key: PublicKey,
+
+    /// A bech32 encoded Radix Babylon account address
+    pub address: String,
+
+    /// The value of the last HD path component, the account index.
+    pub index: HDPathComponentValue,
+
+    /// The HD path which was used to derive the public key.
+    pub path: AccountPath,
+
+    /// ID used to identify that two accounts have been derived from the same mnemonic - does not reveal any secrets.
+    pub factor_source_id: FactorSourceID,
+}
+
+impl Account {
+    /// Derives the [`WatchOnlyAccount`] at `path` using the `mnemonic` and BIP-39
+    /// `passphrase` (can be the empty string), i.e. only the public key and
+    /// address of the account.
+    ///
+    /// The private key must be derived to compute the public key, but it is
+    /// zeroized immediately after, and never returned.
+    pub fn derive_public_only(
+        mnemonic: &Mnemonic,
+        passphrase: impl Into<Passphrase>,
+        path: &AccountPath,
+    ) -> Result<WatchOnlyAccount> {
+        let seed = Seed::from_mnemonic(mnemonic, passphrase);
+        let network_id = path.network_id();
+        let (private_key, public_key) = derive_ed25519_key_pair(seed.as_bytes(), &path.0.inner())?;
+        drop(private_key);
+        let address = derive_address(&public_key, &network_id)?;
+
+        Ok(WatchOnlyAccount {
+            network_id,
+            public_key,
+            address,
+            index: path.account_index(),
+            path: path.clone(),
+            factor_source_id: FactorSourceID::from_seed(&seed),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn watch_only_eq_full_account() {
+        let mnemonic = Mnemonic::from(Mnemonic24Words::test_0());
+        for network_id in NetworkID::all() {
+            let path = AccountPath::new(&network_id, 1);
+            let account = Account::derive_unwrap(&mnemonic, "radix", &path);
+            let watch_only = Account::derive_public_only(&mnemonic, "radix", &path).unwrap();
+            assert_eq!(watch_only.address, account.address);
+            assert_eq!(watch_only.public_key, account.public_key);
+            assert_eq!(watch_only.factor_source_id, account.factor_source_id);
+            assert_eq!(watch_only.network_id, network_id);
+            assert_eq!(watch_only.index, 1);
+            assert_eq!(watch_only.path, path);
+            assert_eq!(watch_only.to_string(), account.to_string());
+        }
+    }
+
+    #[test]
+    fn watch_only_has_no_private_key() {
+        let path = AccountPath::new(&NetworkID::Mainnet, 0);
+        let watch_only =
+            Account::derive_public_only(&Mnemonic24Words::test_0(), "", &path).unwrap();
+        assert_eq!(
+            watch_only.address,
+            "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+        );
+        let debug = format!("{watch_only:?}");
+        assert!(!debug.contains("7b21b62816c6349293abc3a8c37470f917ae621ada2eb8d5124250e83b78f7ef"));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::prelude::*;
+use std::{io::Write, ops::Range};
+
+/// The header row written by [`write_csv`], the fields of a
+/// [`CanonicalAccountRecord`] in order, except the network, which is the same
+/// for all rows. The last column is only included if private keys are.
+const CSV_HEADER: [&str; 6] = [
+    "index",
+    "address",
+    "public_key",
+    "path",
+    "factor_source_id",
+    "private_key",
+];
+
+/// Derives accounts on `network_id` for every index in `range` and writes them
+/// as CSV to `writer`, one row per account preceded by a header row.
+///
+/// Each account is written as soon as it is derived and zeroized right after,
+/// so memory usage is bounded no matter the size of `range`, making this
+/// suitable for very large exports. The BIP-39 seed is computed only once.
+///
+/// The private key column is only included if `include_private_key` is `true`.
+pub fn write_csv<W: Write>(
+    mut writer: W,
+    mnemonic: &Mnemonic,
+    passphrase: impl Into<Passphrase>,
+    network_id: &NetworkID,
+    range: Range<EntityIndex>,
+    include_private_key: bool,
+) -> std::io::Result<()> {
+    writeln!(writer, "{}", csv_header(include_private_key))?;
+
+    let seed = Seed::from_mnemonic(mnemonic, passphrase);
+    let factor_source_id = FactorSourceID::from_seed(&seed);
+    let result = range.into_iter().try_for_each(|index| {
+        let path = AccountPath::new(network_id, index);
+        let mut account = Account::derive_from_seed_with_id(&seed, &factor_source_id, &path)
+            .map_err(std::io::Error::other)?;
+        let mut row = csv_row(&account, include_private_key);
+        let result = writeln!(writer, "{row}");
+        row.zeroize();
+        account.zeroize();
+        result
+    });
+    drop(seed);
+    result?;
+    writer.flush()
+}
+
+/// The header row of the CSV written by [`write_csv`], matching the columns of
+/// [`csv_row`].
+pub fn csv_header(include_private_key: bool) -> String {
+    let column_count = if include_private_key { 6 } else { 5 };
+    CSV_HEADER[..column_count].join(",")
+}
+
+/// Formats `account` as a row of the CSV written by [`write_csv`], without a
+/// trailing newline.
+///
+/// None of the values can contain a comma, quote or newline, thus no quoting
+/// or escaping is needed.
+pub fn csv_row(account: &Account, include_private_key: bool) -> String {
+    let record = CanonicalAccountRecord::from(account).include_private_key(include_private_key);
+    let mut values = record
+        .fields()
+        .into_iter()
+        .filter(|(name, _)| *name != "network")
+        .map(|(_, value)| value)
+        .collect::<Vec<_>>();
+    let row = values.join(",");
+    values.zeroize();
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn csv(include_private_key: bool) -> String {
+        let mut bytes = Vec::<u8>::new();
+        write_csv(
+            &mut bytes,
+            &Mnemonic24Words::test_0(),
+            "",
+            &NetworkID::Mainnet,
+            0..2,
+            include_private_key,
+        )
+        .unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn without_private_key() {
+        let csv = csv(false);
+        let rows = csv.lines().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0], "index,address,public_key,path,factor_source_id");
+        let account = Account::test_0(&NetworkID::Mainnet, 1);
+        assert_eq!(
+            rows[2],
+            format!(
+                "1,{},{},m/44H/1022H/1H/525H/1460H/1H,{}",
+                account.address,
+                account.public_key.to_hex(),
+                account.factor_source_id
+            )
+        );
+    }
+
+    #[test]
+    fn header_matches_canonical_record() {
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        let names = CanonicalAccountRecord::from(&account)
+            .fields()
+            .into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| *name != "network")
+            .collect::<Vec<_>>();
+        assert_eq!(csv_header(true), names.join(","));
+    }
+
+    #[test]
+    fn with_private_key() {
+        let csv = csv(true);
+        let rows = csv.lines().collect::<Vec<_>>();
+        assert_eq!(
+            rows[0],
+            "index,address,public_key,path,factor_source_id,private_key"
+        );
+        let account = Account::test_0(&NetworkID::Mainnet, 0);
+        assert!(rows[1].ends_with(&format!(",{}", account.private_key.to_hex())));
+        assert_eq!(rows[1].split(',').count(), 6);
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use js_sys::Reflect;
+use wallet_compatible_derivation::prelude::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+const MNEMONIC: &str = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate";
+
+fn get(object: &JsValue, key: &str) -> JsValue {
+    Reflect::get(object, &key.into()).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn derive_readme_account() {
+    let account = derive_account(MNEMONIC, "radix", 1, 0, false).unwrap();
+    assert_eq!(
+        get(&account, "address").as_string().unwrap(),
+        "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8"
+    );
+    assert_eq!(
+        get(&account, "path").as_string().unwrap(),
+        "m/44H/1022H/1H/525H/1460H/0H"
+    );
+    assert!(get(&account, "private_key").is_undefined());
+}
+
+#[wasm_bindgen_test]
+fn derive_readme_account_include_private_key() {
+    let account = derive_account(MNEMONIC, "radix", 1, 0, true).unwrap();
+    assert_eq!(
+        get(&account, "private_key").as_string().unwrap(),
+        "cf52dbc7bb2663223e99fb31799281b813b939440a372d0aa92eb5f5b8516003"
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_mnemonic_shorthand_is_rejected() {
+    assert!(derive_account("__test_0", "", 1, 0, false).is_err());
+}
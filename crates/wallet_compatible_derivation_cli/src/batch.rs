@@ -0,0 +1,153 @@
+use crate::config::{parse_network, Config};
+use crate::format::*;
+use crate::interlock::*;
+use std::str::FromStr;
+use wallet_compatible_derivation::prelude::*;
+use zeroize::Zeroize;
+
+/// The optional header row of a batch CSV file.
+pub(crate) const BATCH_CSV_HEADER: &str = "mnemonic,passphrase,network,start,count";
+
+/// Derives the accounts of every row in `csv`, each row being a derivation job
+/// of the form `mnemonic,passphrase,network,start,count`, see [`BATCH_CSV_HEADER`],
+/// the header row is optional and blank lines are ignored.
+///
+/// Returns the output of all rows, grouped by row, and the error of every
+/// malformed row, prefixed with its line number, malformed rows do not abort
+/// the batch.
+///
+/// Accounts are formatted without emoji delimiters if `plain`.
+///
+/// `csv` and each row are zeroized as soon as they have been processed, the
+/// returned output MUST be zeroized by the caller.
+pub(crate) fn derive_batch(
+    mut csv: String,
+    include_private_key: bool,
+    i_understand_mainnet_keys: bool,
+    plain: bool,
+) -> (String, Vec<String>) {
+    let mut rows = csv
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.to_owned()))
+        .collect::<Vec<_>>();
+    csv.zeroize();
+
+    let mut output = String::new();
+    let mut errors = Vec::new();
+    for (line_number, row) in rows.iter_mut() {
+        let is_skipped = row.trim().is_empty() || row.trim() == BATCH_CSV_HEADER;
+        if !is_skipped {
+            let result = parse_batch_row(row).and_then(|config| {
+                check_mainnet_private_keys_interlock(
+                    include_private_key,
+                    &config.network,
+                    i_understand_mainnet_keys,
+                )?;
+                derive_row(&config, include_private_key, plain).map_err(|e| e.to_string())
+            });
+            match result {
+                Ok(mut group) => {
+                    output.push_str(&format!("\n# Row {line_number}\n"));
+                    output.push_str(&group);
+                    group.zeroize();
+                }
+                Err(error) => errors.push(format!("Row {line_number}: {error}")),
+            }
+        }
+        row.zeroize();
+    }
+    (output, errors)
+}
+
+/// Parses a row of the form `mnemonic,passphrase,network,start,count`, the
+/// passphrase can be empty, but cannot contain a comma, and is not trimmed.
+fn parse_batch_row(row: &str) -> Result<Config, String> {
+    let fields = row.split(',').collect::<Vec<_>>();
+    let [mnemonic, passphrase, network, start, count] = fields[..] else {
+        return Err(format!(
+            "Expected 5 comma separated fields '{BATCH_CSV_HEADER}', found {}.",
+            fields.len()
+        ));
+    };
+    let mnemonic =
+        Mnemonic::from_str(mnemonic.trim()).map_err(|_| "Invalid mnemonic.".to_owned())?;
+    let passphrase = Passphrase::from(passphrase.to_owned());
+    let network = parse_network(network.trim()).map_err(|e| e.to_string())?;
+    let (start, count) = (start.trim(), count.trim());
+    let start = start
+        .parse::<u32>()
+        .map_err(|_| format!("Invalid start: '{start}'."))?;
+    let count = count
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid count: '{count}', max 255."))?;
+    Ok(Config {
+        mnemonic: Some(mnemonic),
+        mnemonic_file: None,
+        mnemonic_env: None,
+        seed: None,
+        passphrase,
+        network,
+        start,
+        count,
+    })
+}
+
+/// Derives the accounts of one row, formatted as text below its redacted config.
+fn derive_row(config: &Config, include_private_key: bool, plain: bool) -> Result<String> {
+    let (range, is_truncated) = config.index_range();
+    let mut accounts = config.derive_accounts(range.clone())?;
+    let mut formatted = format_accounts(
+        &accounts,
+        OutputFormat::Text,
+        JsonStyle::Compact,
+        include_private_key,
+        false,
+        false,
+        plain,
+    );
+    accounts.iter_mut().for_each(Zeroize::zeroize);
+    let note = if is_truncated {
+        format!("{}\n", config.truncated_range_message(&range))
+    } else {
+        String::new()
+    };
+    let group = format!("{config}\n{note}{formatted}");
+    formatted.zeroize();
+    Ok(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn malformed_rows_do_not_abort_batch() {
+        let csv = format!(
+            "{BATCH_CSV_HEADER}
+__test_0,,stokenet,0,1
+
+zoo,,stokenet,0,1
+__test_0,,nebunet,0,1
+__test_0,,stokenet,0
+__test_1,,stokenet,0,256
+__test_0,,mainnet,0,1
+"
+        );
+        let (output, errors) = derive_batch(csv, true, false, false);
+        assert_eq!(
+            errors,
+            vec![
+                "Row 4: Invalid mnemonic.".to_owned(),
+                "Row 5: Unsupported or unknown Network ID: 'nebunet'".to_owned(),
+                "Row 6: Expected 5 comma separated fields 'mnemonic,passphrase,network,start,count', found 4.".to_owned(),
+                "Row 7: Invalid count: '256', max 255.".to_owned(),
+                format!("Row 8: {MAINNET_PRIVATE_KEYS_NOT_ACKNOWLEDGED}"),
+            ]
+        );
+        assert!(output.starts_with("\n# Row 2\n"));
+        assert!(output
+            .contains("account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"));
+        assert!(!output.contains("# Row 8"));
+    }
+}
@@ -0,0 +1,43 @@
+use crate::qr::*;
+use wallet_compatible_derivation::prelude::*;
+
+/// Formats `account` as a bounded, print friendly block containing the index,
+/// network and address of the account, and the address as a QR code, e.g. to
+/// print a physical reference sheet of account addresses.
+///
+/// Never includes any private key.
+pub(crate) fn format_account_bundle(account: &Account) -> String {
+    let mut lines = vec![
+        format!("Account #{} ({})", account.index, account.network_id),
+        account.address.clone(),
+        String::new(),
+    ];
+    lines.extend(qr_code(&account.address).lines().map(str::to_owned));
+
+    let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let border = "─".repeat(width + 2);
+    let body = lines
+        .iter()
+        .map(|l| format!("│ {}{} │", l, " ".repeat(width - l.chars().count())))
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!("\n┌{border}┐\n{body}\n└{border}┘\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_account::*;
+
+    #[test]
+    fn bundle_is_bounded_and_excludes_private_key() {
+        let account = test_account(&NetworkID::Mainnet, 0);
+        let bundle = format_account_bundle(&account);
+        let lines = bundle.trim().lines().collect::<Vec<_>>();
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|l| l.chars().count() == width));
+        assert_eq!(lines[1].trim_matches(['│', ' ']), "Account #0 (Mainnet)");
+        assert_eq!(lines[2].trim_matches(['│', ' ']), account.address);
+        assert!(!bundle.contains(&account.private_key.to_hex()));
+    }
+}
@@ -1,7 +1,7 @@
 use clap::Args;
 use wallet_compatible_derivation::prelude::*;
 
-use std::str::FromStr;
+use std::{ops::Range, path::PathBuf, str::FromStr};
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// A run configuration for the binary `wallet_compatible_derivation_cli`.
@@ -10,22 +10,52 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 ///
 /// As soon as this run configuration is no longer needed, it should be zeroized
 /// and dropped.
-#[derive(Debug, Args, Zeroize, ZeroizeOnDrop)]
+#[derive(Args, ZeroizeOnDrop)]
 pub(crate) struct Config {
     /// The mnemonic you wanna use to derive accounts with.
     #[arg(
         short = 'm',
         long = "mnemonic", 
-        help = "The BIP-39 Mnemonic ('Seed Phrase') used to derive the accounts. Must be a 24 word English Mnemonic.", value_parser = Mnemonic24Words::from_str
+        help = "The BIP-39 Mnemonic ('Seed Phrase') used to derive the accounts. Must be a 12, 15, 18, 21 or 24 word English Mnemonic.", value_parser = Mnemonic::from_str,
+        required_unless_present_any = ["seed", "mnemonic_file", "mnemonic_env"]
     )]
-    pub(crate) mnemonic: Mnemonic24Words,
+    pub(crate) mnemonic: Option<Mnemonic>,
+
+    /// A file to read the mnemonic from, keeping it out of shell history.
+    #[arg(
+        long = "mnemonic-file",
+        value_name = "PATH",
+        help = "Read the mnemonic from the file at PATH instead of passing it with `--mnemonic`, which leaks it into your shell history and the process list.",
+        conflicts_with_all = ["mnemonic", "mnemonic_env", "seed"]
+    )]
+    #[zeroize(skip)]
+    pub(crate) mnemonic_file: Option<PathBuf>,
+
+    /// An environment variable to read the mnemonic from.
+    #[arg(
+        long = "mnemonic-env",
+        value_name = "VAR",
+        help = "Read the mnemonic from the environment variable VAR instead of passing it with `--mnemonic`, which leaks it into your shell history and the process list.",
+        conflicts_with_all = ["mnemonic", "seed"]
+    )]
+    #[zeroize(skip)]
+    pub(crate) mnemonic_env: Option<String>,
+
+    /// The BIP-39 seed, as an alternative to the mnemonic and passphrase.
+    #[arg(
+        long = "seed",
+        help = "Advanced: The 64 bytes BIP-39 seed as hex, instead of a mnemonic and passphrase, e.g. if you only have the seed from the backup of another wallet.",
+        value_parser = Seed::from_hex,
+        conflicts_with_all = ["mnemonic", "passphrase"]
+    )]
+    pub(crate) seed: Option<Seed>,
 
     /// An optional BIP-39 passphrase.
-    #[arg(short = 'p', long = "passphrase", help = "Advanced: An optional BIP-39 passphrase, use the empty string if you don't need one. Often referred to as 'the 25th word'. For extra security.", default_value_t = String::new())]
-    pub(crate) passphrase: String,
+    #[arg(short = 'p', long = "passphrase", help = "Advanced: An optional BIP-39 passphrase, use the empty string if you don't need one. Often referred to as 'the 25th word'. For extra security.", value_parser = Passphrase::from_str, default_value = "")]
+    pub(crate) passphrase: Passphrase,
 
     /// The Network you want to derive accounts on.
-    #[arg(short = 'n', long = "network", help = "The ID of the Radix Network the derived accounts should be used with.", value_parser = NetworkID::from_str, default_value_t = NetworkID::Mainnet)]
+    #[arg(short = 'n', long = "network", visible_alias = "network-id", alias = "network_id", help = "The ID of the Radix Network the derived accounts should be used with, either its name or its numeric ID, e.g. `mainnet` or `1`.", value_parser = parse_network, default_value_t = NetworkID::Mainnet)]
     #[zeroize(skip)]
     pub(crate) network: NetworkID,
 
@@ -48,52 +78,337 @@ pub(crate) struct Config {
     pub(crate) count: u8,
 }
 
+impl Zeroize for Config {
+    /// Zeroizes the mnemonic, seed and passphrase in place. Unlike
+    /// `Option::zeroize` the mnemonic and seed are not replaced by `None`,
+    /// since that overwrites them with the bytes of a `None`, whose payload is
+    /// unspecified, rather than leaving zeroes where the entropy and seed were.
+    fn zeroize(&mut self) {
+        self.mnemonic.iter_mut().for_each(Zeroize::zeroize);
+        self.seed.iter_mut().for_each(Zeroize::zeroize);
+        self.passphrase.zeroize();
+        self.start.zeroize();
+        self.count.zeroize();
+    }
+}
+
+/// Parses a `NetworkID` from either its name, e.g. `"stokenet"`, or its
+/// numeric ID, e.g. `"2"`.
+pub(crate) fn parse_network(s: &str) -> Result<NetworkID> {
+    match s.parse::<HDPathComponentValue>() {
+        Ok(id) => NetworkID::try_from(id),
+        Err(_) => NetworkID::from_str(s)
+            .map_err(|_| Error::UnsupportedOrUnknownNetworkIDFromStr(s.to_owned())),
+    }
+}
+
+impl std::fmt::Debug for Config {
+    /// Redacts the mnemonic, seed and passphrase, even if they are not set.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Config")
+            .field("mnemonic", &format_args!("***redacted***"))
+            .field("seed", &format_args!("***redacted***"))
+            .field("passphrase", &format_args!("***redacted***"))
+            .field("network", &self.network)
+            .field("start", &self.start)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Config {
+    /// Formats the config with the mnemonic and passphrase redacted, use
+    /// `to_string_include_secrets(true)` if you really need them.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_string_include_secrets(false))
+    }
+}
+
+impl Config {
+    /// The range of account indices to derive, `start..start + count`, clamped
+    /// so that it does not go past the largest valid account index `2^31 - 1`,
+    /// together with whether it was truncated, i.e. fewer than `count` accounts
+    /// will be derived.
+    pub(crate) fn index_range(&self) -> (Range<u32>, bool) {
+        let max_end = harden(0);
+        let end = self.start.saturating_add(self.count as u32).min(max_end);
+        let start = self.start.min(end);
+        let is_truncated = end - start < self.count as u32;
+        (Range { start, end }, is_truncated)
+    }
+
+    /// A message explaining that fewer accounts than requested will be derived,
+    /// since the requested range goes past the largest valid account index.
+    pub(crate) fn truncated_range_message(&self, range: &Range<u32>) -> String {
+        format!(
+            "Only deriving {} of the {} requested accounts, since the largest valid account index is {}.",
+            range.len(),
+            self.count,
+            harden(0) - 1
+        )
+    }
+
+    /// The BIP-39 seed, either `seed` or computed from `mnemonic` and `passphrase`.
+    pub(crate) fn seed(&self) -> Seed {
+        match (&self.seed, &self.mnemonic) {
+            (Some(seed), _) => seed.clone(),
+            (None, Some(mnemonic)) => Seed::from_mnemonic(mnemonic, &self.passphrase),
+            (None, None) => unreachable!("Either a mnemonic or a seed is required."),
+        }
+    }
+
+    /// Derives the accounts at every index in `range`, computing the seed once.
+    pub(crate) fn derive_accounts(&self, range: Range<u32>) -> Result<Vec<Account>> {
+        self.derive_accounts_with_progress(range, || {})
+    }
+
+    /// Like `derive_accounts` but calls `on_derived` after every derived account,
+    /// e.g. to report progress.
+    pub(crate) fn derive_accounts_with_progress(
+        &self,
+        range: Range<u32>,
+        mut on_derived: impl FnMut(),
+    ) -> Result<Vec<Account>> {
+        let seed = self.seed();
+        let mut accounts = Vec::with_capacity(range.len());
+        Account::for_each_in_range_from_seed(&seed, &self.network, range, |account| {
+            accounts.push(account);
+            on_derived();
+        })?;
+        Ok(accounts)
+    }
+
+    /// Formats the config, only including the mnemonic and passphrase, or the
+    /// seed, in plaintext if `include_secrets` is `true`, else the mnemonic is
+    /// redacted to its first and last word and a fingerprint (the start of its
+    /// `FactorSourceID` without passphrase), and the passphrase is redacted to
+    /// whether it is set or not. A seed is redacted to only its fingerprint.
+    pub(crate) fn to_string_include_secrets(&self, include_secrets: bool) -> String {
+        let secrets = match (&self.seed, &self.mnemonic) {
+            (Some(seed), _) if include_secrets => format!(
+                "Seed: {}",
+                seed.as_bytes()
+                    .iter()
+                    .map(|b| format!("{:02x}", b))
+                    .collect::<String>()
+            ),
+            (Some(seed), _) => format!(
+                "Seed: <set> (fingerprint: {})",
+                fingerprint(&FactorSourceID::from_seed(seed))
+            ),
+            (None, Some(mnemonic)) if include_secrets => format!(
+                "Mnemonic: {}\nPassphrase: {}",
+                mnemonic.phrase(),
+                self.passphrase.as_ref()
+            ),
+            (None, Some(mnemonic)) => format!(
+                "Mnemonic: {}\nPassphrase: {}",
+                redacted_mnemonic(mnemonic),
+                self.redacted_passphrase()
+            ),
+            (None, None) => unreachable!("Either a mnemonic or a seed is required."),
+        };
+        format!(
+            "{}\nNetwork: {}\nStart: {}\nCount: {}",
+            secrets, self.network, self.start, self.count
+        )
+    }
+
+    fn redacted_passphrase(&self) -> String {
+        if self.passphrase.is_empty() {
+            "<empty>".to_owned()
+        } else {
+            "<set>".to_owned()
+        }
+    }
+}
+
+/// The start of the hex of `factor_source_id`, enough to tell wallets apart.
+fn fingerprint(factor_source_id: &FactorSourceID) -> String {
+    factor_source_id.to_hex()[..8].to_owned()
+}
+
+fn redacted_mnemonic(mnemonic: &Mnemonic) -> String {
+    let mut phrase = mnemonic.phrase();
+    let words = phrase.split_whitespace().collect::<Vec<_>>();
+    let fingerprint = fingerprint(&FactorSourceID::from_mnemonic(mnemonic, ""));
+    let redacted = format!(
+        "{} … {} (fingerprint: {})",
+        words[0],
+        words[words.len() - 1],
+        fingerprint
+    );
+    phrase.zeroize();
+    redacted
+}
+
 #[cfg(test)]
 mod tests {
-    use std::{
-        ffi::{CStr, CString},
-        mem,
-        ops::Range,
-    };
+    use std::{mem, ops::Range};
 
     use super::*;
 
+    #[test]
+    fn display_redacts_secrets() {
+        let config = Config {
+            mnemonic: Some(Mnemonic::from_str("__test_0").unwrap()),
+            mnemonic_file: None,
+            mnemonic_env: None,
+            seed: None,
+            passphrase: "radix".parse().unwrap(),
+            network: NetworkID::Mainnet,
+            start: 0,
+            count: 1,
+        };
+        let display = config.to_string();
+        assert_eq!(display, "Mnemonic: bright … mandate (fingerprint: 6facb00a)\nPassphrase: <set>\nNetwork: Mainnet\nStart: 0\nCount: 1");
+        assert!(!display.contains(&config.mnemonic.as_ref().unwrap().phrase()));
+        assert!(!display.contains("radix"));
+
+        let with_secrets = config.to_string_include_secrets(true);
+        assert!(with_secrets.contains(&config.mnemonic.as_ref().unwrap().phrase()));
+        assert!(with_secrets.contains("Passphrase: radix"));
+    }
+
+    fn config(start: u32, count: u8) -> Config {
+        Config {
+            mnemonic: Some(Mnemonic::from_str("__test_0").unwrap()),
+            mnemonic_file: None,
+            mnemonic_env: None,
+            seed: None,
+            passphrase: Passphrase::default(),
+            network: NetworkID::Mainnet,
+            start,
+            count,
+        }
+    }
+
+    #[test]
+    fn index_range() {
+        assert_eq!(config(3, 2).index_range(), (3..5, false));
+        let max = 2u32.pow(31) - 1;
+        assert_eq!(config(max - 1, 2).index_range(), (max - 1..max + 1, false));
+    }
+
+    #[test]
+    fn index_range_is_clamped() {
+        let max = 2u32.pow(31) - 1;
+        assert_eq!(config(max - 1, 5).index_range(), (max - 1..max + 1, true));
+
+        let config = config(u32::MAX - 1, 5);
+        let (range, is_truncated) = config.index_range();
+        assert!(is_truncated);
+        assert_eq!(range.len(), 0);
+        assert!(config.derive_accounts(range.clone()).unwrap().is_empty());
+        assert_eq!(
+            config.truncated_range_message(&range),
+            "Only deriving 0 of the 5 requested accounts, since the largest valid account index is 2147483647."
+        );
+    }
+
+    #[test]
+    fn debug_redacts_secrets() {
+        let mnemonic = Mnemonic::from_str("__test_0").unwrap();
+        let config = Config {
+            mnemonic: Some(mnemonic.clone()),
+            mnemonic_file: None,
+            mnemonic_env: None,
+            seed: None,
+            passphrase: Passphrase::from_str("radix").unwrap(),
+            network: NetworkID::Stokenet,
+            start: 3,
+            count: 7,
+        };
+        let debug = format!("{config:?}");
+        assert_eq!(
+            debug,
+            "Config { mnemonic: ***redacted***, seed: ***redacted***, passphrase: ***redacted***, network: Stokenet, start: 3, count: 7 }"
+        );
+        assert!(!debug.contains(&mnemonic.phrase()));
+        assert!(!debug.contains("radix"));
+    }
+
+    #[test]
+    fn seed_instead_of_mnemonic() {
+        let seed_hex = "c33bd429c56f70dd56cb64406d37e863a72cfa07804d6abfad13f1325f2be578d455213534c6bb9e05247744c3d868bcd4148adcf46d1acfb7784c54e38edc5a";
+        let config = Config {
+            mnemonic: None,
+            mnemonic_file: None,
+            mnemonic_env: None,
+            seed: Some(Seed::from_hex(seed_hex).unwrap()),
+            passphrase: Passphrase::default(),
+            network: NetworkID::Mainnet,
+            start: 0,
+            count: 1,
+        };
+        assert_eq!(
+            config.derive_accounts(0..1).unwrap()[0].address,
+            "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8"
+        );
+        let display = config.to_string();
+        assert!(display.starts_with("Seed: <set> (fingerprint: "));
+        assert!(!display.contains(seed_hex));
+        assert!(config
+            .to_string_include_secrets(true)
+            .contains(&format!("Seed: {seed_hex}")));
+    }
+
+    #[test]
+    fn parse_network_name_or_id() {
+        assert_eq!(parse_network("mainnet").unwrap(), NetworkID::Mainnet);
+        assert_eq!(parse_network("Stokenet").unwrap(), NetworkID::Stokenet);
+        assert_eq!(parse_network("1").unwrap(), NetworkID::Mainnet);
+        assert_eq!(parse_network("2").unwrap(), NetworkID::Stokenet);
+        assert_eq!(
+            parse_network("3"),
+            Err(Error::UnsupportedOrUnknownNetworkID(3))
+        );
+        assert_eq!(
+            parse_network("nebunet"),
+            Err(Error::UnsupportedOrUnknownNetworkIDFromStr(
+                "nebunet".to_owned()
+            ))
+        );
+    }
+
     #[test]
     fn zeroize_config() {
         let mut config = Config {
-            mnemonic: Mnemonic24Words::from_str("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote").unwrap(),
-            passphrase: "radix".to_owned(),
+            mnemonic: Some(Mnemonic::from_str("zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote").unwrap()),
+            mnemonic_file: None,
+            mnemonic_env: None,
+            seed: None,
+            passphrase: "radix".parse().unwrap(),
             network: NetworkID::Mainnet,
             start: 0,
             count: 1,
         };
 
-        let mnemonic_view = &config.mnemonic as *const _ as *const u8;
+        let mnemonic_view = config.mnemonic.as_ref().unwrap() as *const _ as *const u8;
         let mnemonic_range = Range {
             start: 0,
-            end: mem::size_of::<Mnemonic24Words>() as isize,
+            end: mem::size_of::<[u8; 32]>() as isize,
         };
         for i in mnemonic_range.clone() {
             assert_eq!(unsafe { *mnemonic_view.offset(i) }, 0xff);
         }
 
-        let passphrase_ptr = CString::new(config.passphrase.as_str())
-            .unwrap()
-            .as_c_str()
-            .as_ptr();
+        let passphrase_view = config.passphrase.as_ref().as_ptr();
+        let passphrase_len = config.passphrase.as_ref().len();
 
         config.zeroize();
 
-        for i in mnemonic_range.clone() {
-            assert_eq!(unsafe { *mnemonic_view.offset(i) }, 0x00);
-        }
+        let entropy = mnemonic_range
+            .clone()
+            .map(|i| unsafe { *mnemonic_view.offset(i) })
+            .collect::<Vec<_>>();
+        assert!(entropy.iter().all(|b| *b == 0));
 
-        let again_back_passphrase_c_str = unsafe { CStr::from_ptr(passphrase_ptr) };
-        let again_back_passphrase_c_string: CString =
-            again_back_passphrase_c_str.try_into().unwrap();
-        let again_back_passphrase_string: String = again_back_passphrase_c_string
-            .to_string_lossy()
-            .into_owned();
-        assert_ne!(again_back_passphrase_string, "radix");
+        // Zeroizing a `String` clears it, but keeps its allocation.
+        assert!(config.passphrase.is_empty());
+        let passphrase = (0..passphrase_len)
+            .map(|i| unsafe { *passphrase_view.add(i) })
+            .collect::<Vec<_>>();
+        assert!(passphrase.iter().all(|b| *b == 0));
     }
 }
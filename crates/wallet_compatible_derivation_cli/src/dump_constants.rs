@@ -0,0 +1,63 @@
+use wallet_compatible_derivation::prelude::*;
+
+/// Formats all the constants used in the derivation paths, hardened and as
+/// hex, one per line, and each network's path component and HRP suffix, as a
+/// reference for developers of other implementations to diff against.
+pub(crate) fn dump_constants() -> String {
+    let constant = |name: &str, value: HDPathComponentValue| {
+        format!("{name}: {}H / {:#010X}", unhardened(value), value)
+    };
+    let mut lines = vec![
+        constant("PURPOSE", PURPOSE),
+        constant("COINTYPE", COINTYPE),
+        constant(
+            "ENTITY_KIND_ACCOUNT",
+            EntityKind::Account.hardened_hd_component_value(),
+        ),
+        constant(
+            "ENTITY_KIND_IDENTITY",
+            EntityKind::Identity.hardened_hd_component_value(),
+        ),
+        constant(
+            "KEY_KIND_SIGN_TX",
+            KeyKind::TransactionSigning.hardened_hd_component_value(),
+        ),
+        constant(
+            "KEY_KIND_AUTH",
+            KeyKind::AuthenticationSigning.hardened_hd_component_value(),
+        ),
+        constant("GETID", EntityKind::GetId.hardened_hd_component_value()),
+    ];
+    lines.extend(NetworkID::all().into_iter().map(|network| {
+        format!(
+            "{} (HRP suffix: {})",
+            constant(
+                &format!("NETWORK_{}", network.to_string().to_uppercase()),
+                network.hardened_hd_component_value()
+            ),
+            network.hrp_suffix()
+        )
+    }));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump() {
+        assert_eq!(
+            dump_constants(),
+            "PURPOSE: 44H / 0x8000002C
+COINTYPE: 1022H / 0x800003FE
+ENTITY_KIND_ACCOUNT: 525H / 0x8000020D
+ENTITY_KIND_IDENTITY: 618H / 0x8000026A
+KEY_KIND_SIGN_TX: 1460H / 0x800005B4
+KEY_KIND_AUTH: 1678H / 0x8000068E
+GETID: 365H / 0x8000016D
+NETWORK_MAINNET: 1H / 0x80000001 (HRP suffix: rdx)
+NETWORK_STOKENET: 2H / 0x80000002 (HRP suffix: tdx_2_)"
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::config::Config;
+use std::{
+    ops::Range,
+    time::{Duration, Instant},
+};
+use wallet_compatible_derivation::prelude::*;
+use zeroize::Zeroize;
+
+/// The number of accounts derived to estimate how long deriving all requested
+/// accounts takes, see `estimate`.
+pub(crate) const ESTIMATE_SAMPLE_SIZE: usize = 100;
+
+/// The estimated time to derive `count` accounts, given that computing the seed,
+/// which is done once, took `seed_derivation`, and deriving `sample_size`
+/// accounts took `sample`.
+pub(crate) fn extrapolate(
+    seed_derivation: Duration,
+    sample: Duration,
+    sample_size: usize,
+    count: usize,
+) -> Duration {
+    if sample_size == 0 {
+        return seed_derivation;
+    }
+    seed_derivation + sample.mul_f64(count as f64 / sample_size as f64)
+}
+
+/// Times deriving the first (at most) `ESTIMATE_SAMPLE_SIZE` accounts of `range`
+/// and returns a one line estimate of how long deriving all of `range` takes.
+///
+/// The sample accounts are zeroized immediately, and never printed.
+pub(crate) fn estimate(config: &Config, range: Range<u32>) -> Result<String> {
+    let count = range.len();
+    let sample_size = count.min(ESTIMATE_SAMPLE_SIZE);
+
+    let started_at = Instant::now();
+    let seed = config.seed();
+    let seed_derivation = started_at.elapsed();
+
+    let started_at = Instant::now();
+    let mut sample = Vec::with_capacity(sample_size);
+    Account::for_each_in_range_from_seed(
+        &seed,
+        &config.network,
+        range.start..range.start + sample_size as u32,
+        |account| sample.push(account),
+    )?;
+    let sample_elapsed = started_at.elapsed();
+    sample.iter_mut().for_each(Zeroize::zeroize);
+
+    let eta = extrapolate(seed_derivation, sample_elapsed, sample_size, count);
+    Ok(format!(
+        "Estimated time to derive {count} accounts on {}: {:.2}s (derived a sample of {sample_size} in {:.2}s)",
+        config.network,
+        eta.as_secs_f64(),
+        (seed_derivation + sample_elapsed).as_secs_f64()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolate_linearly_after_seed() {
+        assert_eq!(
+            extrapolate(
+                Duration::from_millis(500),
+                Duration::from_millis(100),
+                100,
+                255
+            ),
+            Duration::from_millis(755)
+        );
+        assert_eq!(
+            extrapolate(Duration::from_millis(500), Duration::ZERO, 0, 0),
+            Duration::from_millis(500)
+        );
+    }
+}
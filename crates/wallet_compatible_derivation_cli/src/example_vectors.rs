@@ -0,0 +1,126 @@
+use wallet_compatible_derivation::prelude::*;
+
+/// A documented test vector, which is used by the README and the unit tests
+/// of the `wallet_compatible_derivation` library.
+pub(crate) struct ExampleVector {
+    pub(crate) mnemonic: &'static str,
+    pub(crate) passphrase: &'static str,
+    pub(crate) network: NetworkID,
+    pub(crate) index: HDPathComponentValue,
+    pub(crate) address: &'static str,
+}
+
+const MNEMONIC_0: &str = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate";
+const MNEMONIC_1: &str = "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote";
+
+pub(crate) const EXAMPLE_VECTORS: [ExampleVector; 6] = [
+    ExampleVector {
+        mnemonic: MNEMONIC_0,
+        passphrase: "radix",
+        network: NetworkID::Mainnet,
+        index: 0,
+        address: "account_rdx12yy8n09a0w907vrjyj4hws2yptrm3rdjv84l9sr24e3w7pk7nuxst8",
+    },
+    ExampleVector {
+        mnemonic: MNEMONIC_0,
+        passphrase: "radix",
+        network: NetworkID::Mainnet,
+        index: 1,
+        address: "account_rdx129a9wuey40lducsf6yu232zmzk5kscpvnl6fv472r0ja39f3hced69",
+    },
+    ExampleVector {
+        mnemonic: MNEMONIC_0,
+        passphrase: "",
+        network: NetworkID::Mainnet,
+        index: 0,
+        address: "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4",
+    },
+    ExampleVector {
+        mnemonic: MNEMONIC_0,
+        passphrase: "",
+        network: NetworkID::Stokenet,
+        index: 0,
+        address: "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8",
+    },
+    ExampleVector {
+        mnemonic: MNEMONIC_1,
+        passphrase: "",
+        network: NetworkID::Mainnet,
+        index: 0,
+        address: "account_rdx128dp80lfaywaqchg4fqymy76pqvl20mjmpw08839yfh4qz6us4ltaj",
+    },
+    ExampleVector {
+        mnemonic: MNEMONIC_1,
+        passphrase: "foo",
+        network: NetworkID::Stokenet,
+        index: 1,
+        address: "account_tdx_2_129peacgfcj99m8ty9s2z09u7n3dhf6ps0n6mlz5ttex7mnfrzyjtt5",
+    },
+];
+
+impl ExampleVector {
+    /// Derives the account of this example vector, returning the derived address.
+    pub(crate) fn derive_address(&self) -> String {
+        let mnemonic: Mnemonic24Words = self
+            .mnemonic
+            .parse()
+            .expect("Example vectors should only use valid mnemonics.");
+        let path = AccountPath::new(&self.network, self.index);
+        Account::derive_unwrap(&mnemonic, self.passphrase, &path)
+            .address
+            .clone()
+    }
+}
+
+/// Runs the example vector at position `n` in `EXAMPLE_VECTORS`, printing the
+/// expected vs actual address, returns `true` if they match.
+pub(crate) fn run_example_vector(n: usize) -> bool {
+    let Some(vector) = EXAMPLE_VECTORS.get(n) else {
+        eprintln!(
+            "No example vector #{n}, valid values are 0 to {}.",
+            EXAMPLE_VECTORS.len() - 1
+        );
+        return false;
+    };
+    let actual = vector.derive_address();
+    let is_match = actual == vector.address;
+    println!(
+        "Example vector #{n} (NOT your accounts, only for verifying this build)
+Mnemonic: {}
+Passphrase: {:?}
+Network: {}
+Index: {}
+Expected address: {}
+Actual address:   {}
+{}",
+        vector.mnemonic,
+        vector.passphrase,
+        vector.network,
+        vector.index,
+        vector.address,
+        actual,
+        if is_match {
+            "✅ MATCH"
+        } else {
+            "❌ MISMATCH"
+        }
+    );
+    is_match
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_example_vectors_match() {
+        for n in 0..EXAMPLE_VECTORS.len() {
+            assert!(run_example_vector(n));
+        }
+    }
+
+    #[test]
+    fn unknown_example_vector() {
+        assert!(!run_example_vector(EXAMPLE_VECTORS.len()));
+    }
+}
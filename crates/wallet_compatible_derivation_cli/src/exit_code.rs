@@ -0,0 +1,155 @@
+use clap::error::{ContextKind, ContextValue, ErrorKind};
+use wallet_compatible_derivation::prelude::*;
+
+/// The stable exit codes of the CLI, documented in the README, letting scripts
+/// distinguish kinds of failure. `0` is success.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ExitCode {
+    /// Any failure without a more specific code, e.g. a mismatch of
+    /// `--expect-address` or malformed rows of `--input-csv`.
+    Failure = 1,
+
+    /// Invalid usage, e.g. an unknown or conflicting argument, as reported by clap.
+    Usage = 2,
+
+    /// Invalid mnemonic, or seed.
+    InvalidMnemonic = 3,
+
+    /// Invalid or unsupported network.
+    InvalidNetwork = 4,
+
+    /// Invalid derivation path, or path component.
+    InvalidPath = 5,
+
+    /// Failed to derive a key or encode an address.
+    DerivationFailed = 6,
+
+    /// Failed to read or write a file.
+    Io = 7,
+
+    /// An interactive prompt failed or was cancelled.
+    Prompt = 8,
+}
+
+impl From<&Error> for ExitCode {
+    fn from(value: &Error) -> Self {
+        match value {
+            Error::InvalidMnemonic
+            | Error::UnknownMnemonicWord { .. }
+            | Error::InvalidMnemonicChecksum
+            | Error::EmptyMnemonic
+            | Error::UnsupportedMnemonicTooFewWords { .. }
+            | Error::InvalidSeedHex
+            | Error::InvalidEntropyHex
+            | Error::DerivationRequestMissingMnemonic => Self::InvalidMnemonic,
+
+            Error::UnsupportedOrUnknownNetworkID(_)
+            | Error::UnsupportedOrUnknownNetworkIDFromStr(_)
+            | Error::InvalidAddressOrUnknownNetwork(_) => Self::InvalidNetwork,
+
+            Error::UnknownEntityKind(_)
+            | Error::UnknownKeyKind(_)
+            | Error::AccountIndexInGroupTooLarge { .. }
+            | Error::AccountGroupOffsetOutOfBounds { .. }
+            | Error::InvalidOlympiaAccountPath(_)
+            | Error::InvalidBIP32Path(_)
+            | Error::InvalidAccountPath(_)
+            | Error::InvalidAccountPathNonHardenedPathComponent
+            | Error::InvalidAccountPathWrongDepth { .. }
+            | Error::InvalidDepthOfBIP32Path { .. }
+            | Error::InvalidAccountPathWrongValue { .. }
+            | Error::PathIsForDifferentEntityKind { .. }
+            | Error::InvalidAccountPathInvalidValue { .. }
+            | Error::InvalidSecurifiedIndex(_)
+            | Error::InvalidDerivationRange { .. } => Self::InvalidPath,
+
+            Error::KeyDerivationFailed(_) | Error::AddressEncodingFailed(_) => {
+                Self::DerivationFailed
+            }
+
+            Error::InvalidFactorSourceID(_)
+            | Error::GatewayRequestFailed(_)
+            | Error::InvalidKeystore(_)
+            | Error::InvalidAccountBytes(_)
+            | Error::KeystoreDecryptionFailed => Self::Failure,
+        }
+    }
+}
+
+impl ExitCode {
+    /// The exit code of a clap parsing error, `InvalidMnemonic` or
+    /// `InvalidNetwork` if the value of `--mnemonic`/`--seed` or `--network`
+    /// is invalid, else `Usage`.
+    pub(crate) fn of_clap_error(error: &clap::Error) -> Self {
+        if !matches!(
+            error.kind(),
+            ErrorKind::ValueValidation | ErrorKind::InvalidValue
+        ) {
+            return Self::Usage;
+        }
+        match error.get(ContextKind::InvalidArg) {
+            Some(ContextValue::String(arg))
+                if arg.starts_with("--mnemonic") || arg.starts_with("--seed") =>
+            {
+                Self::InvalidMnemonic
+            }
+            Some(ContextValue::String(arg)) if arg.starts_with("--network") => Self::InvalidNetwork,
+            _ => Self::Usage,
+        }
+    }
+}
+
+/// Prints `error` to stderr and exits with `code`.
+pub(crate) fn exit_with(error: impl std::fmt::Display, code: ExitCode) -> ! {
+    eprintln!("Error: {error}");
+    std::process::exit(code as i32)
+}
+
+/// Like `exit_with` with the `ExitCode` of `error`.
+pub(crate) fn exit_with_error(error: Error) -> ! {
+    let code = ExitCode::from(&error);
+    exit_with(error, code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_error() {
+        assert_eq!(
+            ExitCode::from(&Error::InvalidMnemonicChecksum),
+            ExitCode::InvalidMnemonic
+        );
+        assert_eq!(
+            ExitCode::from(&Error::UnsupportedOrUnknownNetworkIDFromStr("x".to_owned())),
+            ExitCode::InvalidNetwork
+        );
+        assert_eq!(
+            ExitCode::from(&Error::InvalidBIP32Path("x".to_owned())),
+            ExitCode::InvalidPath
+        );
+        assert_eq!(
+            ExitCode::from(&Error::KeyDerivationFailed("x".to_owned())),
+            ExitCode::DerivationFailed
+        );
+    }
+
+    #[test]
+    fn codes_are_stable() {
+        assert_eq!(
+            [
+                ExitCode::Failure,
+                ExitCode::Usage,
+                ExitCode::InvalidMnemonic,
+                ExitCode::InvalidNetwork,
+                ExitCode::InvalidPath,
+                ExitCode::DerivationFailed,
+                ExitCode::Io,
+                ExitCode::Prompt,
+            ]
+            .map(|c| c as i32),
+            [1, 2, 3, 4, 5, 6, 7, 8]
+        );
+    }
+}
@@ -0,0 +1,39 @@
+use crate::config::Config;
+use wallet_compatible_derivation::prelude::*;
+
+/// Derives the account at the start index of `config` and checks that its
+/// address is `expected`, returning a message describing the outcome, `Err`
+/// on mismatch. Never includes any keys in the message, which is prefixed with
+/// an emoji unless `plain`.
+pub(crate) fn check_expected_address(
+    config: &Config,
+    expected: &str,
+    plain: bool,
+) -> Result<String, String> {
+    if config.start >= harden(0) {
+        return Err(format!(
+            "The start index must be at most {}, the largest valid account index.",
+            harden(0) - 1
+        ));
+    }
+    let mut accounts = config
+        .derive_accounts(config.start..config.start + 1)
+        .map_err(|e| format!("Failed to derive account: {e}"))?;
+    let account = accounts.remove(0);
+    let path = account.path.to_string();
+    let (matches, mismatch) = if plain { ("", "") } else { ("✅ ", "❌ ") };
+    if account.address == expected.trim() {
+        Ok(format!(
+            "{matches}Address matches, derived at index {} ({path}) on {}: {}",
+            account.index, account.network_id, account.address
+        ))
+    } else {
+        Err(format!(
+            "{mismatch}Address mismatch at index {} ({path}) on {}:\nexpected: {}\nderived:  {}",
+            account.index,
+            account.network_id,
+            expected.trim(),
+            account.address
+        ))
+    }
+}
@@ -0,0 +1,38 @@
+use clap::Args;
+use wallet_compatible_derivation::prelude::*;
+
+use std::str::FromStr;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// The arguments of the `factor-source-id` command, which only needs the
+/// mnemonic and passphrase, no network or account indices.
+///
+/// Contains secrets, thus it implements `Zeroize`.
+#[derive(Debug, Args, Zeroize, ZeroizeOnDrop)]
+pub(crate) struct FactorSourceIdArgs {
+    #[arg(
+        short = 'm',
+        long = "mnemonic",
+        help = "The BIP-39 Mnemonic ('Seed Phrase') to print the FactorSourceID of. Must be a 12, 15, 18, 21 or 24 word English Mnemonic.",
+        value_parser = Mnemonic::from_str
+    )]
+    pub(crate) mnemonic: Mnemonic,
+
+    #[arg(
+        short = 'p',
+        long = "passphrase",
+        help = "Advanced: An optional BIP-39 passphrase, the same mnemonic with different passphrases has different FactorSourceIDs.",
+        value_parser = Passphrase::from_str,
+        default_value = ""
+    )]
+    pub(crate) passphrase: Passphrase,
+}
+
+impl FactorSourceIdArgs {
+    /// The hex of the `FactorSourceID` of the mnemonic and passphrase, derived
+    /// at the `GetID` path, without deriving any account keys. The seed is
+    /// zeroized once the ID has been derived.
+    pub(crate) fn factor_source_id_hex(&self) -> String {
+        FactorSourceID::from_mnemonic(&self.mnemonic, &self.passphrase).to_hex()
+    }
+}
@@ -0,0 +1,353 @@
+use crate::bundle::*;
+use crate::format_account;
+use crate::qr::*;
+use crate::schema::*;
+use clap::ValueEnum;
+use std::io::IsTerminal;
+use wallet_compatible_derivation::prelude::*;
+use zeroize::Zeroize;
+
+/// The format derived accounts are output in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub(crate) enum OutputFormat {
+    /// Human readable blocks, one per account.
+    #[default]
+    Text,
+
+    /// JSON objects, one per account, for scripts to parse, laid out as
+    /// given by `JsonStyle`.
+    Json,
+
+    /// A header row and one row per account.
+    Csv,
+}
+
+/// The layout of `OutputFormat::Json`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub(crate) enum JsonStyle {
+    /// One object per line, without whitespace and not wrapped in an array
+    /// (JSON Lines), e.g. for `jq` to stream.
+    Compact,
+
+    /// An array of objects, indented with one key per line, to read.
+    Pretty,
+}
+
+impl JsonStyle {
+    /// `style` if set, else `Pretty` if the output is a terminal, else
+    /// `Compact`.
+    pub(crate) fn or_default(style: Option<Self>, is_terminal: bool) -> Self {
+        style.unwrap_or(if is_terminal {
+            Self::Pretty
+        } else {
+            Self::Compact
+        })
+    }
+}
+
+/// Like `JsonStyle::or_default` with whether stdout is a terminal, which it is
+/// not considered to be if `to_file`, since the output is written to a file then.
+pub(crate) fn json_style(style: Option<JsonStyle>, to_file: bool) -> JsonStyle {
+    JsonStyle::or_default(style, !to_file && std::io::stdout().is_terminal())
+}
+
+/// Formats all `accounts` in `format`, including their private keys only if
+/// `include_private_key` is `true`. `Json` is laid out as `json_style`. If `bundle` is `true`, `Text` is formatted
+/// as account bundles, see [`format_account_bundle`], else if `qr` is `true`,
+/// each account in `Text` is followed by its address as a QR code. If `plain`,
+/// accounts in `Text` are formatted without emoji delimiters, see
+/// `format_account_plain`.
+///
+/// All intermediary strings are zeroized, the returned string MUST be zeroized
+/// by the caller.
+pub(crate) fn format_accounts(
+    accounts: &[Account],
+    format: OutputFormat,
+    json_style: JsonStyle,
+    include_private_key: bool,
+    bundle: bool,
+    qr: bool,
+    plain: bool,
+) -> String {
+    let mut rows = accounts
+        .iter()
+        .map(|account| match format {
+            OutputFormat::Text if bundle => format_account_bundle(account),
+            OutputFormat::Text if qr => format_account_with_qr(account, include_private_key, plain),
+            OutputFormat::Text => format_account(account, include_private_key, plain),
+            OutputFormat::Json => json_object(account, include_private_key, json_style),
+            OutputFormat::Csv => csv_row(account, include_private_key),
+        })
+        .collect::<Vec<_>>();
+    let separator = match format {
+        OutputFormat::Text => "",
+        OutputFormat::Json if json_style == JsonStyle::Pretty => ",\n",
+        OutputFormat::Json => "\n",
+        OutputFormat::Csv => "\n",
+    };
+    let mut body = rows.join(separator);
+    rows.zeroize();
+    if !body.is_empty() && format != OutputFormat::Text {
+        body.push('\n');
+    }
+    let output = match format {
+        OutputFormat::Text => body.clone(),
+        OutputFormat::Json if json_style == JsonStyle::Pretty => format!("[\n{body}]\n"),
+        OutputFormat::Json => body.clone(),
+        OutputFormat::Csv => format!("{}\n{body}", csv_header(include_private_key)),
+    };
+    body.zeroize();
+    output
+}
+
+/// Formats `account` like `format_account`, followed by its address, and only
+/// its address, never the private key, as a QR code.
+fn format_account_with_qr(account: &Account, include_private_key: bool, plain: bool) -> String {
+    let mut text = format_account(account, include_private_key, plain);
+    let with_qr = format!("{text}{}\n", qr_code(&account.address));
+    text.zeroize();
+    with_qr
+}
+
+/// The `schema_version`, see [`account_record_schema`], followed by the fields
+/// of the `CanonicalAccountRecord` of `account`, in its order, except that the
+/// path is keyed `hd_path`, on one line if `style` is `Compact`, else indented
+/// for an array. None of the values can contain a quote, backslash or control
+/// character, thus no escaping is needed.
+fn json_object(account: &Account, include_private_key: bool, style: JsonStyle) -> String {
+    let record = CanonicalAccountRecord::from(account).include_private_key(include_private_key);
+    let colon = match style {
+        JsonStyle::Compact => ":",
+        JsonStyle::Pretty => ": ",
+    };
+    let mut fields = record
+        .fields()
+        .into_iter()
+        .map(|(name, mut value)| {
+            let name = if name == "path" { "hd_path" } else { name };
+            let field = if name == "index" {
+                format!(r#""{name}"{colon}{value}"#)
+            } else {
+                format!(r#""{name}"{colon}"{value}""#)
+            };
+            value.zeroize();
+            field
+        })
+        .collect::<Vec<_>>();
+    fields.insert(0, format!(r#""schema_version"{colon}{SCHEMA_VERSION}"#));
+    let object = match style {
+        JsonStyle::Compact => format!("{{{}}}", fields.join(",")),
+        JsonStyle::Pretty => format!("  {{\n    {}\n  }}", fields.join(",\n    ")),
+    };
+    fields.zeroize();
+    object
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plain::*;
+    use std::str::FromStr;
+
+    fn accounts(count: u32) -> Vec<Account> {
+        Account::derive_range(
+            &Mnemonic24Words::from_str("__test_0").unwrap(),
+            "",
+            &NetworkID::Mainnet,
+            0..count,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn json_compact() {
+        let accounts = accounts(2);
+        let json = format_accounts(
+            &accounts,
+            OutputFormat::Json,
+            JsonStyle::Compact,
+            true,
+            false,
+            false,
+            false,
+        );
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"schema_version":1,"index":0,"address":"account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4","network":"Mainnet","public_key":"6224937b15ec4017a036c0bd6999b7fa2b9c2f9452286542fd56f6a3fb6d33ed","hd_path":"m/44H/1022H/1H/525H/1460H/0H","factor_source_id":"6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033","private_key":"7b21b62816c6349293abc3a8c37470f917ae621ada2eb8d5124250e83b78f7ef"}}
+{{"schema_version":1,"index":1,"address":"{}","network":"Mainnet","public_key":"{}","hd_path":"m/44H/1022H/1H/525H/1460H/1H","factor_source_id":"6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033","private_key":"{}"}}
+"#,
+                accounts[1].address,
+                accounts[1].public_key.to_hex(),
+                accounts[1].private_key.to_hex()
+            )
+        );
+        assert!(!json.contains('✨'));
+    }
+
+    #[test]
+    fn json_pretty() {
+        let accounts = accounts(2);
+        let json = format_accounts(
+            &accounts,
+            OutputFormat::Json,
+            JsonStyle::Pretty,
+            false,
+            false,
+            false,
+            false,
+        );
+        assert_eq!(
+            json,
+            format!(
+                r#"[
+  {{
+    "schema_version": 1,
+    "index": 0,
+    "address": "account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4",
+    "network": "Mainnet",
+    "public_key": "6224937b15ec4017a036c0bd6999b7fa2b9c2f9452286542fd56f6a3fb6d33ed",
+    "hd_path": "m/44H/1022H/1H/525H/1460H/0H",
+    "factor_source_id": "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033"
+  }},
+  {{
+    "schema_version": 1,
+    "index": 1,
+    "address": "{}",
+    "network": "Mainnet",
+    "public_key": "{}",
+    "hd_path": "m/44H/1022H/1H/525H/1460H/1H",
+    "factor_source_id": "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033"
+  }}
+]
+"#,
+                accounts[1].address,
+                accounts[1].public_key.to_hex(),
+            )
+        );
+    }
+
+    #[test]
+    fn json_style_defaults_to_pretty_for_terminal() {
+        assert_eq!(JsonStyle::or_default(None, true), JsonStyle::Pretty);
+        assert_eq!(JsonStyle::or_default(None, false), JsonStyle::Compact);
+        assert_eq!(
+            JsonStyle::or_default(Some(JsonStyle::Compact), true),
+            JsonStyle::Compact
+        );
+        assert_eq!(
+            JsonStyle::or_default(Some(JsonStyle::Pretty), false),
+            JsonStyle::Pretty
+        );
+    }
+
+    #[test]
+    fn json_excludes_private_key() {
+        let accounts = accounts(1);
+        let json = format_accounts(
+            &accounts,
+            OutputFormat::Json,
+            JsonStyle::Compact,
+            false,
+            false,
+            false,
+            false,
+        );
+        assert!(!json.contains("private_key"));
+        assert!(!json.contains(&accounts[0].private_key.to_hex()));
+    }
+
+    #[test]
+    fn json_empty() {
+        let empty =
+            |style| format_accounts(&[], OutputFormat::Json, style, false, false, false, false);
+        assert_eq!(empty(JsonStyle::Compact), "");
+        assert_eq!(empty(JsonStyle::Pretty), "[\n]\n");
+    }
+
+    #[test]
+    fn csv() {
+        let accounts = accounts(2);
+        let csv = format_accounts(
+            &accounts,
+            OutputFormat::Csv,
+            JsonStyle::Compact,
+            false,
+            false,
+            false,
+            false,
+        );
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], csv_header(false));
+        assert_eq!(lines[2], csv_row(&accounts[1], false));
+    }
+
+    #[test]
+    fn text_with_qr() {
+        let accounts = accounts(2);
+        let text = format_accounts(
+            &accounts,
+            OutputFormat::Text,
+            JsonStyle::Compact,
+            false,
+            false,
+            true,
+            false,
+        );
+        assert_eq!(
+            text,
+            format!(
+                "{}{}\n{}{}\n",
+                format_account(&accounts[0], false, false),
+                qr_code(&accounts[0].address),
+                format_account(&accounts[1], false, false),
+                qr_code(&accounts[1].address)
+            )
+        );
+    }
+
+    #[test]
+    fn text() {
+        let accounts = accounts(2);
+        assert_eq!(
+            format_accounts(
+                &accounts,
+                OutputFormat::Text,
+                JsonStyle::Compact,
+                false,
+                false,
+                false,
+                false
+            ),
+            format!(
+                "{}{}",
+                format_account(&accounts[0], false, false),
+                format_account(&accounts[1], false, false)
+            )
+        );
+    }
+
+    #[test]
+    fn text_plain() {
+        let accounts = accounts(2);
+        let text = format_accounts(
+            &accounts,
+            OutputFormat::Text,
+            JsonStyle::Compact,
+            true,
+            false,
+            false,
+            true,
+        );
+        assert_eq!(
+            text,
+            format!(
+                "{}{}",
+                format_account_plain(&accounts[0], true),
+                format_account_plain(&accounts[1], true)
+            )
+        );
+        assert!(text.is_ascii());
+    }
+}
@@ -0,0 +1,107 @@
+use wallet_compatible_derivation::prelude::*;
+
+/// The error shown when refusing to output Mainnet private keys without the
+/// `--i-understand-mainnet-keys` acknowledgment.
+pub(crate) const MAINNET_PRIVATE_KEYS_NOT_ACKNOWLEDGED: &str = "Refusing to output Mainnet private keys: anyone who sees them can steal all funds of those accounts. If you really want to, pass `--i-understand-mainnet-keys` together with `--include-private-key`.";
+
+/// The warning printed to stderr before outputting Mainnet private keys, after
+/// the acknowledgment, see `mainnet_private_keys_warning`.
+pub(crate) const MAINNET_PRIVATE_KEYS_WARNING: &str = "WARNING: you are on MAINNET, the private keys output control real funds. Never share them, anyone who sees them can steal all funds of those accounts.";
+
+/// The error shown when refusing to output the entropy of the mnemonic on
+/// Mainnet without the `--i-understand-mainnet-keys` acknowledgment.
+pub(crate) const MAINNET_ENTROPY_NOT_ACKNOWLEDGED: &str = "Refusing to output the entropy of the mnemonic on Mainnet: anyone who sees it controls all accounts of the mnemonic. If you really want to, pass `--i-understand-mainnet-keys` together with `--show-entropy`.";
+
+/// Checks that outputting private keys of accounts on `network` has been
+/// explicitly acknowledged if `network` is Mainnet, returning an error
+/// explaining the required acknowledgment otherwise.
+pub(crate) fn check_mainnet_private_keys_interlock(
+    include_private_key: bool,
+    network: &NetworkID,
+    acknowledged: bool,
+) -> Result<(), &'static str> {
+    if include_private_key && network.is_mainnet() && !acknowledged {
+        Err(MAINNET_PRIVATE_KEYS_NOT_ACKNOWLEDGED)
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that outputting the entropy of the mnemonic, which is as secret as
+/// all private keys, has been explicitly acknowledged if `network` is Mainnet.
+pub(crate) fn check_mainnet_entropy_interlock(
+    network: &NetworkID,
+    acknowledged: bool,
+) -> Result<(), &'static str> {
+    if network.is_mainnet() && !acknowledged {
+        Err(MAINNET_ENTROPY_NOT_ACKNOWLEDGED)
+    } else {
+        Ok(())
+    }
+}
+
+/// The warning to print before outputting private keys of accounts on
+/// `network`, if `include_private_key` and `network` is Mainnet.
+pub(crate) fn mainnet_private_keys_warning(
+    include_private_key: bool,
+    network: &NetworkID,
+) -> Option<&'static str> {
+    (include_private_key && network.is_mainnet()).then_some(MAINNET_PRIVATE_KEYS_WARNING)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mainnet_private_keys_require_acknowledgment() {
+        assert_eq!(
+            check_mainnet_private_keys_interlock(true, &NetworkID::Mainnet, false),
+            Err(MAINNET_PRIVATE_KEYS_NOT_ACKNOWLEDGED)
+        );
+        assert_eq!(
+            check_mainnet_private_keys_interlock(true, &NetworkID::Mainnet, true),
+            Ok(())
+        );
+        assert_eq!(
+            check_mainnet_private_keys_interlock(false, &NetworkID::Mainnet, false),
+            Ok(())
+        );
+        assert_eq!(
+            check_mainnet_private_keys_interlock(true, &NetworkID::Stokenet, false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn mainnet_entropy_requires_acknowledgment() {
+        assert_eq!(
+            check_mainnet_entropy_interlock(&NetworkID::Mainnet, false),
+            Err(MAINNET_ENTROPY_NOT_ACKNOWLEDGED)
+        );
+        assert_eq!(
+            check_mainnet_entropy_interlock(&NetworkID::Mainnet, true),
+            Ok(())
+        );
+        assert_eq!(
+            check_mainnet_entropy_interlock(&NetworkID::Stokenet, false),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn warns_only_of_mainnet_private_keys() {
+        assert_eq!(
+            mainnet_private_keys_warning(true, &NetworkID::Mainnet),
+            Some(MAINNET_PRIVATE_KEYS_WARNING)
+        );
+        assert_eq!(
+            mainnet_private_keys_warning(false, &NetworkID::Mainnet),
+            None
+        );
+        assert_eq!(
+            mainnet_private_keys_warning(true, &NetworkID::Stokenet),
+            None
+        );
+    }
+}
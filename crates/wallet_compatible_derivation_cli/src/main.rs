@@ -1,14 +1,54 @@
+mod batch;
+mod bundle;
 mod config;
+mod dump_constants;
+mod estimate;
+mod example_vectors;
+mod exit_code;
+mod expect_address;
+mod factor_source_id;
+mod format;
+mod interlock;
+mod mnemonic_source;
+mod networks;
+mod paged;
+mod picker;
+mod plain;
+mod progress;
+mod qr;
 mod read_config_from_stdin;
+mod schema;
+mod summary;
+#[cfg(test)]
+mod test_account;
+mod write_output;
+use crate::batch::*;
 use crate::config::Config;
+use crate::dump_constants::*;
+use crate::estimate::*;
+use crate::example_vectors::*;
+use crate::exit_code::*;
+use crate::expect_address::*;
+use crate::factor_source_id::*;
+use crate::format::*;
+use crate::interlock::*;
+use crate::mnemonic_source::*;
+use crate::networks::*;
+use crate::paged::*;
+use crate::picker::*;
+use crate::plain::*;
+use crate::progress::*;
 use crate::read_config_from_stdin::*;
+use crate::schema::*;
+use crate::summary::*;
+use crate::write_output::*;
 
-use clap::{Parser, Subcommand};
+use clap::{error::ErrorKind, CommandFactory, Parser, Subcommand};
 
 use wallet_compatible_derivation::prelude::*;
 
-use pager::Pager;
-use std::{ops::Range, thread, time};
+use rand_core::OsRng;
+use std::{fs, path::PathBuf, time::Instant};
 use zeroize::Zeroize;
 
 #[derive(Parser)]
@@ -26,64 +66,431 @@ struct Cli {
     /// If the PrivateKey of derived accounts is included in output.
     #[arg(short, long, default_value_t = false)]
     pub(crate) include_private_key: bool,
+
+    /// Acknowledge that you understand the risk of outputting Mainnet private
+    /// keys, required together with `--include-private-key` or `--show-entropy`
+    /// on Mainnet.
+    #[arg(long, default_value_t = false)]
+    pub(crate) i_understand_mainnet_keys: bool,
+
+    /// SENSITIVE: Print the 16 to 32 bytes of entropy of the mnemonic as hex, instead
+    /// of deriving any accounts. The entropy is as secret as the mnemonic itself.
+    /// Requires `--i-understand-mainnet-keys` on Mainnet.
+    #[arg(long, default_value_t = false)]
+    pub(crate) show_entropy: bool,
+
+    /// Derives the documented example vector (test vector) with this number,
+    /// and prints the expected vs the actual address, to verify this build.
+    #[arg(long, hide = true)]
+    pub(crate) example_vector: Option<usize>,
+
+    /// Print each account as a bounded block with its index, address and the
+    /// address as a QR code, suitable for printing, e.g. on a label.
+    /// Never includes private keys.
+    #[arg(
+        short,
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["include_private_key", "format"]
+    )]
+    pub(crate) bundle: bool,
+
+    /// The format to output the derived accounts in, `json` and `csv` are
+    /// meant for scripts to parse.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    pub(crate) format: OutputFormat,
+
+    /// The layout of `--format json`: `compact` is one object per line, e.g.
+    /// for `jq` to stream, `pretty` is an indented array. Defaults to `pretty`
+    /// if stdout is a terminal, else `compact`.
+    #[arg(long, value_enum)]
+    pub(crate) json_style: Option<JsonStyle>,
+
+    /// Shorthand for `--format json --json-style compact`.
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["format", "json_style", "json_pretty", "bundle"]
+    )]
+    pub(crate) json: bool,
+
+    /// Shorthand for `--format json --json-style pretty`.
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["format", "json_style", "bundle"]
+    )]
+    pub(crate) json_pretty: bool,
+
+    /// Print the address of each derived account as a QR code, below the
+    /// account, e.g. to scan it on another device. Only the address is encoded,
+    /// never the private key. Requires `--format text`.
+    #[arg(long, default_value_t = false, conflicts_with = "bundle")]
+    pub(crate) qr: bool,
+
+    /// Print all constants used in derivation paths, hardened and as hex, and
+    /// each network's path component and HRP suffix, instead of deriving any
+    /// accounts. Useful to compare other implementations against.
+    #[arg(long, default_value_t = false)]
+    pub(crate) dump_constants: bool,
+
+    /// Print the JSON Schema of the account record objects output with
+    /// `--format json`, instead of deriving any accounts, for downstream
+    /// parsers to validate against.
+    #[arg(long, default_value_t = false)]
+    pub(crate) schema: bool,
+
+    /// After deriving, interactively pick one of the derived accounts to show
+    /// its private key or QR code, or to export it to an encrypted file.
+    #[arg(long, default_value_t = false)]
+    pub(crate) pick: bool,
+
+    /// Write the derived accounts to this file instead of printing them.
+    #[arg(short, long)]
+    pub(crate) output: Option<PathBuf>,
+
+    /// Encrypt the file written to `--output` with a passphrase you will be
+    /// prompted for, using the `age` file format (decrypt with `age -d`).
+    #[arg(short, long, default_value_t = false, requires = "output")]
+    pub(crate) encrypt: bool,
+
+    /// Do not print the summary of how many accounts were derived and how
+    /// long it took, which is otherwise printed to stderr, unless `--format`
+    /// is `json` or `csv`. Also hides the progress shown on stderr while
+    /// deriving more than 100 accounts.
+    #[arg(short, long, default_value_t = false)]
+    pub(crate) quiet: bool,
+
+    /// Instead of printing any accounts, derive only the account at the start
+    /// index and check that its address is this one, exiting with a non-zero
+    /// status if it is not. Never prints any keys.
+    #[arg(
+        long,
+        value_name = "ADDRESS",
+        conflicts_with_all = ["show_entropy", "bundle", "qr", "pick", "output"]
+    )]
+    pub(crate) expect_address: Option<String>,
+
+    /// Instead of printing any accounts, time deriving a sample of at most 100
+    /// accounts and print an estimate of how long deriving all requested
+    /// accounts takes. The sample accounts are never printed.
+    #[arg(
+        long,
+        default_value_t = false,
+        conflicts_with_all = ["show_entropy", "bundle", "qr", "pick", "output", "expect_address"]
+    )]
+    pub(crate) estimate: bool,
+
+    /// Print each account as a plain block of aligned `Label: value` lines,
+    /// without emoji delimiters, e.g. for logs and CI. This is the default if
+    /// the `NO_COLOR` environment variable is set, or if stdout is not a
+    /// terminal, e.g. when piped, or when writing to `--output`.
+    #[arg(long, visible_alias = "no-color", default_value_t = false)]
+    pub(crate) plain: bool,
+
+    /// Derive the accounts of every row of this CSV file, each row being
+    /// `mnemonic,passphrase,network,start,count`, instead of a single mnemonic.
+    /// Malformed rows are reported without aborting the batch.
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["show_entropy", "bundle", "format", "qr", "pick", "encrypt", "expect_address", "estimate"]
+    )]
+    pub(crate) input_csv: Option<PathBuf>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     NoPager(Config),
     Pager,
+    /// Print only the FactorSourceID of a mnemonic and passphrase, to confirm
+    /// that two mnemonics are the same, without deriving any accounts.
+    FactorSourceId(FactorSourceIdArgs),
+    /// Generate a new 24 words mnemonic, using the randomness of the operating
+    /// system, and print it. Write it down and store it safely.
+    GenerateMnemonic,
+    /// List every supported network, with its name and numeric ID, both
+    /// accepted by `--network`, its logical name and the HRP suffix of its
+    /// addresses.
+    Networks,
 }
 
-fn paged() {
-    Pager::new().setup();
-
-    // Pager setup is a bit slow, if we don't add this terribly ugly hacky
-    // sleep, the output of inquire is not shown.
-    thread::sleep(time::Duration::from_millis(250));
+impl Cli {
+    /// Expands the `--json` and `--json-pretty` shorthands into `format` and
+    /// `json_style`, so that the rest of `main` only has to look at those.
+    fn expand_json_shorthands(mut self) -> Self {
+        if self.json || self.json_pretty {
+            self.format = OutputFormat::Json;
+            self.json_style = Some(if self.json_pretty {
+                JsonStyle::Pretty
+            } else {
+                JsonStyle::Compact
+            });
+        }
+        self
+    }
 }
 
 fn main() {
-    let cli = Cli::parse();
+    let cli = Cli::try_parse()
+        .unwrap_or_else(|error| {
+            if !error.use_stderr() {
+                // `--help` or `--version`
+                error.exit()
+            }
+            let _ = error.print();
+            std::process::exit(ExitCode::of_clap_error(&error) as i32)
+        })
+        .expand_json_shorthands();
+    if cli.qr && cli.format != OutputFormat::Text {
+        Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "`--qr` can only be used with `--format text`",
+            )
+            .exit();
+    }
+    if let Some(n) = cli.example_vector {
+        let is_match = run_example_vector(n);
+        std::process::exit(if is_match {
+            0
+        } else {
+            ExitCode::Failure as i32
+        });
+    }
+    if cli.dump_constants {
+        println!("{}", dump_constants());
+        return;
+    }
+    if cli.schema {
+        print!("{}", account_record_schema());
+        return;
+    }
+    if let Some(path) = cli.input_csv {
+        let csv = fs::read_to_string(&path).unwrap_or_else(|e| {
+            exit_with(
+                format!("Failed to read input CSV '{}': {e}", path.display()),
+                ExitCode::Io,
+            )
+        });
+        let (mut output, errors) = derive_batch(
+            csv,
+            cli.include_private_key,
+            cli.i_understand_mainnet_keys,
+            use_plain(cli.plain, cli.output.is_some()),
+        );
+        match cli.output {
+            Some(path) => {
+                if let Err(e) = write_output(&path, &output, None) {
+                    output.zeroize();
+                    exit_with(
+                        format!("Failed to write output to '{}': {e}", path.display()),
+                        ExitCode::Io,
+                    );
+                }
+                println!("Wrote derived accounts to: {}", path.display());
+            }
+            None => print!("{output}"),
+        }
+        output.zeroize();
+        errors.iter().for_each(|error| eprintln!("{error}"));
+        std::process::exit(if errors.is_empty() {
+            0
+        } else {
+            ExitCode::Failure as i32
+        });
+    }
     let command = cli.command.unwrap_or(Commands::Pager);
     let mut config = match command {
         Commands::NoPager(c) => Ok(c),
         Commands::Pager => {
             paged();
-            read_config_from_stdin()
+            match read_config_from_stdin() {
+                Ok(config) => Ok(config),
+                Err((message, code)) => exit_with(message, code),
+            }
         }
+        Commands::FactorSourceId(mut args) => {
+            println!("{}", args.factor_source_id_hex());
+            args.zeroize();
+            return;
+        }
+        Commands::GenerateMnemonic => {
+            let mut phrase = Mnemonic24Words::generate(OsRng).phrase();
+            println!("{phrase}");
+            phrase.zeroize();
+            return;
+        }
+        Commands::Networks => {
+            print!("{}", format_networks());
+            return;
+        }
+    }
+    .unwrap_or_else(|error| exit_with_error(error));
+    if let Err((message, code)) = read_mnemonic_source(&mut config) {
+        config.zeroize();
+        exit_with(message, code);
+    }
+
+    if cli.show_entropy {
+        let Some(mnemonic) = config.mnemonic.as_ref() else {
+            eprintln!("`--show-entropy` requires a mnemonic, not a seed.");
+            config.zeroize();
+            std::process::exit(ExitCode::Failure as i32);
+        };
+        if let Err(error) =
+            check_mainnet_entropy_interlock(&config.network, cli.i_understand_mainnet_keys)
+        {
+            eprintln!("{error}");
+            config.zeroize();
+            std::process::exit(ExitCode::Failure as i32);
+        }
+        println!("Entropy: {}", *mnemonic.to_entropy_hex());
+        config.zeroize();
+        return;
+    }
+
+    if let Some(expected) = cli.expect_address {
+        let outcome = check_expected_address(&config, &expected, use_plain(cli.plain, false));
+        config.zeroize();
+        match outcome {
+            Ok(message) => println!("{message}"),
+            Err(message) => {
+                eprintln!("{message}");
+                std::process::exit(ExitCode::Failure as i32);
+            }
+        }
+        return;
+    }
+
+    if cli.estimate {
+        let (range, is_truncated) = config.index_range();
+        if is_truncated {
+            eprintln!("{}", config.truncated_range_message(&range));
+        }
+        let estimate = estimate(&config, range);
+        config.zeroize();
+        match estimate {
+            Ok(estimate) => println!("{estimate}"),
+            Err(error) => exit_with_error(error),
+        }
+        return;
     }
-    .expect("Valid config");
 
     let include_private_key = cli.include_private_key;
+    if let Err(error) = check_mainnet_private_keys_interlock(
+        include_private_key,
+        &config.network,
+        cli.i_understand_mainnet_keys,
+    ) {
+        eprintln!("{error}");
+        config.zeroize();
+        std::process::exit(ExitCode::Failure as i32);
+    }
+    if let Some(warning) = mainnet_private_keys_warning(include_private_key, &config.network) {
+        eprintln!("{warning}");
+    }
+
+    let mut encryption_passphrase = if cli.encrypt {
+        match read_encryption_passphrase_from_stdin() {
+            Ok(passphrase) => Some(passphrase),
+            Err(e) => {
+                config.zeroize();
+                exit_with(e, ExitCode::Prompt);
+            }
+        }
+    } else {
+        None
+    };
 
-    let start = config.start;
-    let count = config.count as u32;
-    let end = start + count;
-    for index in (Range { start, end }) {
-        let account_path = AccountPath::new(&config.network, index);
-        let mut account = Account::derive(&config.mnemonic, &config.passphrase, &account_path);
-        print_account(&account, include_private_key);
-        account.zeroize();
+    let (range, is_truncated) = config.index_range();
+    if is_truncated {
+        eprintln!("{}", config.truncated_range_message(&range));
+    }
+    let started_at = Instant::now();
+    let mut progress = Progress::stderr_if_shown(range.len(), cli.quiet);
+    let mut accounts = config
+        .derive_accounts_with_progress(range, || {
+            if let Some(progress) = progress.as_mut() {
+                progress.tick()
+            }
+        })
+        .unwrap_or_else(|error| {
+            config.zeroize();
+            encryption_passphrase.zeroize();
+            exit_with_error(error)
+        });
+    if let Some(progress) = progress {
+        progress.finish();
+    }
+    let plain = use_plain(cli.plain, cli.output.is_some());
+    let mut output = format_accounts(
+        &accounts,
+        cli.format,
+        json_style(cli.json_style, cli.output.is_some()),
+        include_private_key,
+        cli.bundle,
+        cli.qr,
+        plain,
+    );
+    if cli.output.is_none() {
+        print!("{output}");
+    }
+    if !cli.pick {
+        accounts.iter_mut().for_each(Zeroize::zeroize);
+    }
+    if !cli.quiet && cli.format == OutputFormat::Text {
+        eprintln!(
+            "{}",
+            elapsed_summary(accounts.len(), &config.network, started_at.elapsed())
+        );
     }
 
+    let network = config.network.clone();
     config.zeroize();
 
     drop(config);
+
+    if let Some(path) = cli.output {
+        if let Err(e) = write_output(&path, &output, encryption_passphrase.take()) {
+            output.zeroize();
+            accounts.iter_mut().for_each(Zeroize::zeroize);
+            exit_with(
+                format!("Failed to write output to '{}': {e}", path.display()),
+                ExitCode::Io,
+            );
+        }
+        println!("Wrote derived accounts to: {}", path.display());
+    }
+    output.zeroize();
+    encryption_passphrase.zeroize();
+
+    if cli.pick {
+        let picked = pick_accounts(
+            &accounts,
+            &network,
+            cli.i_understand_mainnet_keys,
+            use_plain(cli.plain, false),
+        );
+        accounts.iter_mut().for_each(Zeroize::zeroize);
+        if let Err(e) = picked {
+            exit_with(e, ExitCode::Prompt);
+        }
+    }
 }
 
 const WIDTH: usize = 50;
 
-fn print_account(account: &Account, include_private_key: bool) {
+/// Formats `account` between emoji delimiters, or as a plain block of aligned
+/// lines if `plain`, see `format_account_plain`.
+fn format_account(account: &Account, include_private_key: bool, plain: bool) -> String {
+    if plain {
+        return format_account_plain(account, include_private_key);
+    }
     let delimiter = "✨".repeat(WIDTH);
     let header_delimiter = "🔮".repeat(WIDTH);
     let header = ["✅ CREATED ACCOUNT ✅", &header_delimiter].join("\n");
     let account_string = account.to_string_include_private_key(include_private_key);
-    let output = [
-        delimiter.clone(),
-        header,
-        format!("{account_string}"),
-        delimiter,
-    ]
-    .join("\n");
-    println!("\n{output}");
+    let output = [delimiter.clone(), header, account_string, delimiter].join("\n");
+    format!("\n{output}\n")
 }
@@ -0,0 +1,65 @@
+use crate::{config::Config, exit_code::ExitCode};
+use wallet_compatible_derivation::prelude::*;
+
+use std::{fs, str::FromStr};
+use zeroize::Zeroize;
+
+/// Reads the mnemonic of `config` from the file of `--mnemonic-file` or the
+/// environment variable of `--mnemonic-env`, if either is set, into
+/// `config.mnemonic`. Clap has already validated that at most one of them is
+/// set, and not together with `--mnemonic` or `--seed`.
+///
+/// On failure returns a message, which never contains the phrase, and the
+/// `ExitCode` to exit with.
+pub(crate) fn read_mnemonic_source(config: &mut Config) -> Result<(), (String, ExitCode)> {
+    let phrase = if let Some(path) = config.mnemonic_file.as_ref() {
+        fs::read_to_string(path).map_err(|e| {
+            (
+                format!("Failed to read mnemonic file '{}': {e}", path.display()),
+                ExitCode::Io,
+            )
+        })?
+    } else if let Some(var) = config.mnemonic_env.as_ref() {
+        std::env::var(var).map_err(|e| {
+            (
+                format!("Failed to read mnemonic from environment variable '{var}': {e}"),
+                ExitCode::Usage,
+            )
+        })?
+    } else {
+        return Ok(());
+    };
+    let mnemonic = parse_mnemonic_zeroizing(phrase).map_err(|e| {
+        let code = ExitCode::from(&e);
+        (format!("Invalid mnemonic: {e}"), code)
+    })?;
+    config.mnemonic = Some(mnemonic);
+    Ok(())
+}
+
+/// Parses `phrase`, ignoring surrounding whitespace such as a trailing newline,
+/// and zeroizes it, whether or not it is a valid mnemonic.
+fn parse_mnemonic_zeroizing(mut phrase: String) -> Result<Mnemonic> {
+    let mnemonic = Mnemonic::from_str(phrase.trim());
+    phrase.zeroize();
+    mnemonic
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_trims_whitespace() {
+        let phrase = Mnemonic::from_str("__test_0").unwrap().phrase();
+        assert!(
+            parse_mnemonic_zeroizing(format!("  {phrase}\n")).unwrap()
+                == Mnemonic::from_str(&phrase).unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(parse_mnemonic_zeroizing("zoo zoo".to_owned()).is_err());
+    }
+}
@@ -0,0 +1,32 @@
+use wallet_compatible_derivation::prelude::*;
+
+/// Formats a table of every supported network, with its name as accepted by
+/// `--network`, its discriminant, which is also accepted, its logical name and
+/// the HRP suffix of its addresses, one network per row.
+pub(crate) fn format_networks() -> String {
+    let header = ["NAME", "ID", "LOGICAL NAME", "HRP SUFFIX"].map(str::to_owned);
+    let rows = std::iter::once(header)
+        .chain(NetworkID::all().into_iter().map(|n| {
+            [
+                n.to_string(),
+                n.discriminant().to_string(),
+                n.logical_name(),
+                n.hrp_suffix(),
+            ]
+        }))
+        .collect::<Vec<_>>();
+    let widths = (0..4)
+        .map(|column| rows.iter().map(|row| row[column].len()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    rows.iter()
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>()
+                .join("  ");
+            format!("{}\n", line.trim_end())
+        })
+        .collect()
+}
@@ -0,0 +1,103 @@
+use pager::Pager;
+use std::{
+    env,
+    ffi::OsStr,
+    path::{Path, PathBuf},
+    thread, time,
+};
+
+/// Pagers we try, in order, if the `PAGER` environment variable is not set.
+const FALLBACK_PAGERS: [&str; 2] = ["less", "more"];
+
+/// Sends all subsequent output to a pager, if one is available, else prints a
+/// notice to stderr and leaves stdout untouched, so output is printed directly.
+pub(crate) fn paged() {
+    let Some(pager) = find_pager(env::var("PAGER").ok(), env::var_os("PATH")) else {
+        eprintln!("No pager found (set `PAGER` or install `less`), printing directly.");
+        return;
+    };
+    Pager::with_pager(&pager).setup();
+
+    // Pager setup is a bit slow, if we don't add this terribly ugly hacky
+    // sleep, the output of inquire is not shown.
+    thread::sleep(time::Duration::from_millis(250));
+}
+
+/// Returns the pager command to use, which is the value of `pager_env` if set
+/// and its program is found, else the first of `FALLBACK_PAGERS` found in
+/// `path_env`, else `None`.
+fn find_pager(pager_env: Option<String>, path_env: Option<impl AsRef<OsStr>>) -> Option<String> {
+    let path_env = path_env?;
+    let is_available = |command: &str| {
+        command
+            .split_whitespace()
+            .next()
+            .is_some_and(|program| find_in_path(program, path_env.as_ref()).is_some())
+    };
+    pager_env.filter(|p| is_available(p)).or_else(|| {
+        FALLBACK_PAGERS
+            .into_iter()
+            .find(|p| is_available(p))
+            .map(str::to_owned)
+    })
+}
+
+/// Returns the path to `program` if it is an existing file, either directly
+/// (if `program` contains a path separator) or in any of the directories of
+/// `path_env`.
+fn find_in_path(program: &str, path_env: &OsStr) -> Option<PathBuf> {
+    let program_path = Path::new(program);
+    if program_path.components().count() > 1 {
+        return program_path.is_file().then(|| program_path.to_path_buf());
+    }
+    env::split_paths(path_env)
+        .flat_map(|dir| {
+            let candidate = dir.join(program);
+            [candidate.with_extension("exe"), candidate]
+        })
+        .find(|candidate| candidate.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn dir_with_programs(name: &str, programs: &[&str]) -> PathBuf {
+        let dir = env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        for program in programs {
+            fs::write(dir.join(program), "").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn no_pager_found() {
+        let dir = dir_with_programs("wallet_compatible_derivation_cli_no_pager", &[]);
+        assert_eq!(find_pager(None, Some(&dir)), None);
+        assert_eq!(find_pager(Some("bat".to_owned()), Some(&dir)), None);
+        assert_eq!(find_pager(None, None::<&OsStr>), None);
+    }
+
+    #[test]
+    fn fallback_pager_found() {
+        let dir = dir_with_programs("wallet_compatible_derivation_cli_more", &["more"]);
+        assert_eq!(find_pager(None, Some(&dir)), Some("more".to_owned()));
+        assert_eq!(
+            find_pager(Some("bat".to_owned()), Some(&dir)),
+            Some("more".to_owned())
+        );
+    }
+
+    #[test]
+    fn pager_env_preferred() {
+        let dir = dir_with_programs("wallet_compatible_derivation_cli_bat", &["bat", "less"]);
+        assert_eq!(
+            find_pager(Some("bat --plain".to_owned()), Some(&dir)),
+            Some("bat --plain".to_owned())
+        );
+        assert_eq!(find_pager(None, Some(&dir)), Some("less".to_owned()));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::bundle::*;
+use crate::format_account;
+use crate::interlock::*;
+use crate::plain::*;
+use crate::read_config_from_stdin::*;
+use crate::write_output::*;
+use inquire::{error::InquireResult, Select, Text};
+use std::path::PathBuf;
+use wallet_compatible_derivation::prelude::*;
+use zeroize::Zeroize;
+
+/// An action the user can perform on an account picked with [`pick_accounts`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    ShowPrivateKey,
+    ShowQRCode,
+    ExportEncrypted,
+    PickAnother,
+    Done,
+}
+
+impl Action {
+    /// The actions available on accounts on `network`, leaving out the actions
+    /// revealing the private key unless the Mainnet private keys interlock is
+    /// satisfied, see `check_mainnet_private_keys_interlock`.
+    fn available(network: &NetworkID, i_understand_mainnet_keys: bool) -> Vec<Self> {
+        let reveals_private_key =
+            check_mainnet_private_keys_interlock(true, network, i_understand_mainnet_keys).is_ok();
+        [
+            Self::ShowPrivateKey,
+            Self::ShowQRCode,
+            Self::ExportEncrypted,
+            Self::PickAnother,
+            Self::Done,
+        ]
+        .into_iter()
+        .filter(|a| reveals_private_key || !a.reveals_private_key())
+        .collect()
+    }
+
+    /// Whether this action prints or writes the private key of the account.
+    fn reveals_private_key(&self) -> bool {
+        matches!(self, Self::ShowPrivateKey | Self::ExportEncrypted)
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::ShowPrivateKey => "Show account including private key",
+            Self::ShowQRCode => "Show address as QR code",
+            Self::ExportEncrypted => "Export account to encrypted file",
+            Self::PickAnother => "Pick another account",
+            Self::Done => "Done",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// The text of an account in the list of accounts to pick from.
+fn account_option(account: &Account) -> String {
+    format!("#{} {}", account.index, account.address)
+}
+
+/// Interactively lets the user pick one of the derived `accounts`, by index and
+/// address, and then perform actions on it, e.g. showing its private key or
+/// exporting it to an encrypted file, until the user is done.
+///
+/// The actions revealing the private key are only offered on Mainnet if
+/// `i_understand_mainnet_keys`, just like `--include-private-key`.
+pub(crate) fn pick_accounts(
+    accounts: &[Account],
+    network: &NetworkID,
+    i_understand_mainnet_keys: bool,
+    plain: bool,
+) -> InquireResult<()> {
+    let actions = Action::available(network, i_understand_mainnet_keys);
+    loop {
+        let options = accounts.iter().map(account_option).collect::<Vec<_>>();
+        let account = &accounts[Select::new("Pick an account", options).raw_prompt()?.index];
+        loop {
+            match Select::new("What do you want to do with it?", actions.clone()).prompt()? {
+                Action::ShowPrivateKey => {
+                    let mut account_string = format_account(account, true, plain);
+                    print!("{account_string}");
+                    account_string.zeroize();
+                }
+                Action::ShowQRCode => print!("{}", format_account_bundle(account)),
+                Action::ExportEncrypted => export_encrypted(account)?,
+                Action::PickAnother => break,
+                Action::Done => return Ok(()),
+            }
+        }
+    }
+}
+
+/// Asks for a path and a passphrase, and writes `account` including its private
+/// key to an encrypted file at that path.
+fn export_encrypted(account: &Account) -> InquireResult<()> {
+    let path = PathBuf::from(Text::new("File to export account to:").prompt()?);
+    let encryption_passphrase = read_encryption_passphrase_from_stdin()?;
+    let mut account_string = format_account_plain(account, true);
+    let result = write_output(&path, &account_string, Some(encryption_passphrase));
+    account_string.zeroize();
+    result?;
+    println!("Wrote account to: {}", path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_account::*;
+
+    #[test]
+    fn account_option_is_index_and_address() {
+        let account = test_account(&NetworkID::Mainnet, 1);
+        assert_eq!(account_option(&account), format!("#1 {}", account.address));
+    }
+
+    #[test]
+    fn mainnet_private_key_actions_require_acknowledgment() {
+        assert_eq!(
+            Action::available(&NetworkID::Mainnet, false),
+            vec![Action::ShowQRCode, Action::PickAnother, Action::Done]
+        );
+        let all = vec![
+            Action::ShowPrivateKey,
+            Action::ShowQRCode,
+            Action::ExportEncrypted,
+            Action::PickAnother,
+            Action::Done,
+        ];
+        assert_eq!(Action::available(&NetworkID::Mainnet, true), all);
+        assert_eq!(Action::available(&NetworkID::Stokenet, false), all);
+    }
+}
@@ -0,0 +1,82 @@
+use std::{ffi::OsString, io::IsTerminal};
+use wallet_compatible_derivation::prelude::*;
+use zeroize::Zeroize;
+
+/// Whether to print plain output, without emoji delimiters, which is the case
+/// if `plain` (`--plain` or `--no-color`) is set, if the `NO_COLOR` environment
+/// variable is set to a non empty value, see <https://no-color.org>, or if the
+/// output is not a terminal, e.g. when piped to a file or captured in CI logs.
+pub(crate) fn should_use_plain(plain: bool, no_color: Option<OsString>, is_terminal: bool) -> bool {
+    plain || no_color.is_some_and(|v| !v.is_empty()) || !is_terminal
+}
+
+/// Like `should_use_plain` with the `NO_COLOR` environment variable, and whether
+/// the output is a terminal, stdout is not considered a terminal if
+/// `to_file`, since the output is written to a file then.
+pub(crate) fn use_plain(plain: bool, to_file: bool) -> bool {
+    should_use_plain(
+        plain,
+        std::env::var_os("NO_COLOR"),
+        !to_file && std::io::stdout().is_terminal(),
+    )
+}
+
+/// Formats `account` as a block of `Label: value` lines, with the values
+/// aligned, without any delimiters, only including the private key if
+/// `include_private_key` is `true`.
+///
+/// The returned string MUST be zeroized by the caller.
+pub(crate) fn format_account_plain(account: &Account, include_private_key: bool) -> String {
+    let mut text = account.to_string_include_private_key(include_private_key);
+    let lines = text
+        .lines()
+        .filter_map(|line| line.split_once(": "))
+        .collect::<Vec<_>>();
+    let width = lines
+        .iter()
+        .map(|(label, _)| label.len())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut rows = lines
+        .into_iter()
+        .map(|(label, value)| format!("{:<width$} {value}", format!("{label}:")))
+        .collect::<Vec<_>>();
+    text.zeroize();
+    let formatted = format!("\n{}\n", rows.join("\n"));
+    rows.zeroize();
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_account::*;
+
+    #[test]
+    fn plain_if_flag_no_color_or_not_terminal() {
+        assert!(!should_use_plain(false, None, true));
+        assert!(!should_use_plain(false, Some("".into()), true));
+        assert!(should_use_plain(true, None, true));
+        assert!(should_use_plain(false, Some("1".into()), true));
+        assert!(should_use_plain(false, None, false));
+    }
+
+    #[test]
+    fn values_are_aligned() {
+        let account = test_account(&NetworkID::Mainnet, 0);
+        let plain = format_account_plain(&account, true);
+        assert!(plain.is_ascii());
+        let lines = plain.lines().skip(1).collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[1],
+            "Address:          account_rdx128vge9xzep4hsn4pns8qch5uqld2yvx6f3gfff786du7vlk6w6e6k4"
+        );
+        let value_column = "Factor Source ID: ".len();
+        for line in lines {
+            assert_eq!(line.as_bytes()[value_column - 1], b' ');
+            assert_ne!(line.as_bytes()[value_column], b' ');
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::io::{IsTerminal, Stderr, Write};
+
+/// Runs deriving at most this many accounts finish fast enough to not need a
+/// progress indicator. Must be less than `u8::MAX`, the largest `--count`.
+pub(crate) const PROGRESS_THRESHOLD: usize = 100;
+
+/// Whether to show progress while deriving `count` accounts, never if `quiet`
+/// or if stderr is not a terminal, e.g. when output is piped.
+pub(crate) fn should_show_progress(count: usize, quiet: bool, is_terminal: bool) -> bool {
+    !quiet && is_terminal && count > PROGRESS_THRESHOLD
+}
+
+/// A single line progress indicator, `"Derived 42/255 accounts (16%)"`, which
+/// is rewritten in place after every derived account. Only counts accounts,
+/// never prints anything about them.
+pub(crate) struct Progress<W: Write> {
+    out: W,
+    total: usize,
+    derived: usize,
+}
+
+impl Progress<Stderr> {
+    /// A progress indicator on stderr, if it should be shown, see
+    /// `should_show_progress`.
+    pub(crate) fn stderr_if_shown(total: usize, quiet: bool) -> Option<Self> {
+        let stderr = std::io::stderr();
+        should_show_progress(total, quiet, stderr.is_terminal()).then(|| Self::new(stderr, total))
+    }
+}
+
+impl<W: Write> Progress<W> {
+    pub(crate) fn new(out: W, total: usize) -> Self {
+        Self {
+            out,
+            total,
+            derived: 0,
+        }
+    }
+
+    /// Counts one more derived account and rewrites the progress line.
+    pub(crate) fn tick(&mut self) {
+        self.derived += 1;
+        let percent = self.derived * 100 / self.total.max(1);
+        // Progress is best effort, failing to write it must not abort derivation.
+        let _ = write!(
+            self.out,
+            "\rDerived {}/{} accounts ({percent}%)",
+            self.derived, self.total
+        );
+        let _ = self.out.flush();
+    }
+
+    /// Ends the progress line, so that subsequent output starts on a new line.
+    pub(crate) fn finish(mut self) {
+        let _ = writeln!(self.out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emits_total() {
+        let mut out = Vec::new();
+        let mut progress = Progress::new(&mut out, 4);
+        (0..4).for_each(|_| progress.tick());
+        progress.finish();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("\rDerived 1/4 accounts (25%)"));
+        assert!(out.ends_with("\rDerived 4/4 accounts (100%)\n"));
+        assert_eq!(out.matches('\r').count(), 4);
+    }
+
+    #[test]
+    fn shown_only_for_large_counts_on_terminal_unless_quiet() {
+        assert!(should_show_progress(PROGRESS_THRESHOLD + 1, false, true));
+        assert!(!should_show_progress(PROGRESS_THRESHOLD + 1, true, true));
+        assert!(!should_show_progress(PROGRESS_THRESHOLD + 1, false, false));
+        assert!(!should_show_progress(PROGRESS_THRESHOLD, false, true));
+    }
+
+    #[test]
+    fn shown_for_max_count() {
+        let count = u8::MAX as usize;
+        assert!(should_show_progress(count, false, true));
+
+        let mut out = Vec::new();
+        let mut progress = Progress::new(&mut out, count);
+        (0..count).for_each(|_| progress.tick());
+        progress.finish();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("\rDerived 42/255 accounts (16%)"));
+        assert!(out.ends_with("\rDerived 255/255 accounts (100%)\n"));
+    }
+}
@@ -0,0 +1,39 @@
+use qrcode::{render::unicode::Dense1x2, QrCode};
+
+/// Renders `data` as a QR code using unicode half blocks, two QR modules per
+/// character vertically, suitable for printing to a terminal or a label.
+///
+/// Dark modules are rendered as spaces, light modules as blocks, so the QR
+/// code is scannable off terminals with a dark background.
+pub(crate) fn qr_code(data: &str) -> String {
+    QrCode::new(data.as_bytes())
+        .expect("Should always be able to encode an address as a QR code.")
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn qr_code_is_not_empty() {
+        let qr = qr_code("account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8");
+        assert!(qr.lines().count() > 10);
+        // Both dark and light modules are rendered.
+        assert!(qr.contains(' '));
+        assert!(qr.contains('█'));
+    }
+
+    #[test]
+    fn qr_code_is_square() {
+        let qr = qr_code("account_rdx128y6j78mt0aqv6372evz28hrxp8mn06ccddkr7xppc88hyvynvjdwr");
+        let lines = qr.lines().collect::<Vec<_>>();
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|l| l.chars().count() == width));
+        // Two modules per line, with odd module counts the last line is half filled.
+        assert_eq!(lines.len(), width.div_ceil(2));
+    }
+}
@@ -1,48 +1,244 @@
 use crate::config::Config;
-use inquire::{CustomType, Password, Select};
+use crate::exit_code::*;
+use inquire::{
+    error::InquireResult, validator::Validation, CustomType, CustomUserError, InquireError,
+    Password, Select, Text,
+};
+use std::str::FromStr;
 use wallet_compatible_derivation::prelude::*;
+use zeroize::Zeroize;
 
-/// An interactive part of the program which asks user for input, most 
-/// prominently it asks the user for to input the Mnemonic. The user 
+/// An interactive part of the program which asks user for input, most
+/// prominently it asks the user for to input the Mnemonic. The user
 /// MUST be aware of keyloggers on her computer, this software does
 /// not (yet) protect against that. Future iterations of this software
 /// might impl a random order interactive picker of characters/words
 /// allowing user to safeguard against keyloggers.
-pub(crate) fn read_config_from_stdin() -> Result<Config> {
-    let mnemonic = CustomType::<Mnemonic24Words>::new("Input mnemonic: ")
-        .with_formatter(&|m| format!("{}", m))
-        .with_error_message("Please type a valid mnemonic")
-        .with_help_message("Only English 24 word mnemonics are supported.")
+///
+/// Returns a message and `ExitCode::Prompt` if any prompt fails or is
+/// cancelled, after which the inputs read so far are zeroized on drop.
+pub(crate) fn read_config_from_stdin() -> Result<Config, (String, ExitCode)> {
+    let mut phrase = Text::new("Input mnemonic: ")
+        .with_validator(validate_mnemonic)
+        .with_help_message("Only English 12, 15, 18, 21 or 24 word mnemonics are supported.")
         .prompt()
-        .map_err(|_| Error::InvalidMnemonic)?;
+        .map_err(prompt_error)?;
+    let mnemonic = Mnemonic::from_str(&phrase);
+    phrase.zeroize();
+    let mnemonic = mnemonic.map_err(|e| (e.to_string(), ExitCode::from(&e)))?;
 
-    let passphrase = Password::new("Passphrase (can be empty):")
-        .prompt()
-        .unwrap();
+    let passphrase =
+        read_confirmed_passphrase(|message| Password::new(message).without_confirmation().prompt())
+            .map_err(|message| (message, ExitCode::Prompt))?;
 
     let network: NetworkID = Select::new("Choose Network", NetworkID::all())
         .prompt()
-        .expect("Should not be possible to select in invalid network id");
+        .map_err(prompt_error)?;
 
     let start = CustomType::<HDPathComponentValue>::new("Account index start: ")
         .with_formatter(&|i| format!("{}", i))
         .with_error_message("Only non negative integers <= 2,147,483,648 are allowed")
         .with_help_message("Normally you want to start at index `0`.")
         .prompt()
-        .expect("Should not be possible to input an invalid u32");
+        .map_err(prompt_error)?;
 
     let count = CustomType::<u8>::new("Number of accounts to derive: ")
         .with_formatter(&|i| format!("#{}", i))
         .with_error_message("Only non negative integers <= 255 are allowed")
         .with_help_message("If you need more than 255 to be derived, let us know!.")
         .prompt()
-        .expect("Should not be possible to input an invalid u8");
+        .map_err(prompt_error)?;
 
     Ok(Config {
-        mnemonic,
+        mnemonic: Some(mnemonic),
+        mnemonic_file: None,
+        mnemonic_env: None,
+        seed: None,
         passphrase,
         network,
         start,
         count,
     })
 }
+
+/// The message and `ExitCode::Prompt` of a failed or cancelled prompt.
+fn prompt_error(error: InquireError) -> (String, ExitCode) {
+    (error.to_string(), ExitCode::Prompt)
+}
+
+/// The number of times the user is asked to input and confirm the BIP-39
+/// passphrase, before giving up, see `read_confirmed_passphrase`.
+pub(crate) const PASSPHRASE_CONFIRMATION_ATTEMPTS: usize = 3;
+
+/// Asks the user for the BIP-39 passphrase using `prompt`, and then again to
+/// confirm it, since a typo would silently derive the wrong accounts. On
+/// mismatch the user is asked again, at most `PASSPHRASE_CONFIRMATION_ATTEMPTS`
+/// times. The empty passphrase is valid, but must also be confirmed.
+///
+/// `prompt` is called with the message to show, and is injectable for tests.
+/// All mismatching inputs are zeroized. Returns a message if the prompt fails,
+/// or if the passphrases never match.
+fn read_confirmed_passphrase(
+    mut prompt: impl FnMut(&str) -> InquireResult<String>,
+) -> Result<Passphrase, String> {
+    for attempt in 1..=PASSPHRASE_CONFIRMATION_ATTEMPTS {
+        let mut passphrase = prompt("Passphrase (can be empty):").map_err(|e| e.to_string())?;
+        let mut confirmation = match prompt("Confirm passphrase:") {
+            Ok(confirmation) => confirmation,
+            Err(error) => {
+                passphrase.zeroize();
+                return Err(error.to_string());
+            }
+        };
+        let is_match = passphrase == confirmation;
+        confirmation.zeroize();
+        if is_match {
+            return Ok(Passphrase::from(passphrase));
+        }
+        passphrase.zeroize();
+        if attempt < PASSPHRASE_CONFIRMATION_ATTEMPTS {
+            eprintln!("The passphrases don't match, please try again.");
+        }
+    }
+    Err(format!(
+        "The passphrases didn't match {PASSPHRASE_CONFIRMATION_ATTEMPTS} times."
+    ))
+}
+
+/// Asks the user for a passphrase, twice for confirmation, used to encrypt
+/// the output file.
+pub(crate) fn read_encryption_passphrase_from_stdin() -> InquireResult<String> {
+    Password::new("Passphrase to encrypt output file with:")
+        .with_custom_confirmation_message("Confirm passphrase to encrypt output file with:")
+        .with_custom_confirmation_error_message("The passphrases don't match.")
+        .with_validator(inquire::required!(
+            "An empty passphrase would not protect the output file."
+        ))
+        .prompt()
+}
+
+/// Validates the input of the mnemonic prompt, with a specific error message
+/// if the user did not input anything, misspelled a word, suggesting the closest
+/// words, or input a wrong word.
+fn validate_mnemonic(input: &str) -> Result<Validation, CustomUserError> {
+    let message = match Mnemonic::from_str(input) {
+        Ok(_) => return Ok(Validation::Valid),
+        Err(Error::EmptyMnemonic) => {
+            "You did not input anything, please type your mnemonic.".to_owned()
+        }
+        Err(e @ Error::UnknownMnemonicWord { position, .. }) => {
+            match Mnemonic24Words::suggest_corrections(input)
+                .into_iter()
+                .find(|(p, suggestions)| *p == position && !suggestions.is_empty())
+            {
+                Some((_, suggestions)) => {
+                    format!("{e}, did you mean: {}?", suggestions.join(", "))
+                }
+                None => format!("{e}."),
+            }
+        }
+        Err(e @ Error::InvalidMnemonicChecksum) => format!("{e}."),
+        Err(_) => "Please type a valid mnemonic".to_owned(),
+    };
+    Ok(Validation::Invalid(message.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Reads a confirmed passphrase, prompting with `answers` in order, and
+    /// returns it together with the number of unused answers.
+    fn confirm(answers: &[&str]) -> (Result<Passphrase, String>, usize) {
+        let mut answers = answers
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<VecDeque<_>>();
+        let passphrase = read_confirmed_passphrase(|_| {
+            Ok(answers
+                .pop_front()
+                .expect("Should not prompt more than answers."))
+        });
+        (passphrase, answers.len())
+    }
+
+    #[test]
+    fn passphrase_confirmed() {
+        let (passphrase, unused) = confirm(&["radix", "radix"]);
+        assert_eq!(passphrase.unwrap().as_ref(), "radix");
+        assert_eq!(unused, 0);
+
+        let (passphrase, _) = confirm(&["", ""]);
+        assert!(passphrase.unwrap().is_empty());
+    }
+
+    #[test]
+    fn passphrase_mismatch_then_match() {
+        let (passphrase, unused) = confirm(&["radix", "radxi", "radix", "radix"]);
+        assert_eq!(passphrase.unwrap().as_ref(), "radix");
+        assert_eq!(unused, 0);
+    }
+
+    #[test]
+    fn passphrase_repeated_mismatch_fails() {
+        let (passphrase, unused) = confirm(&["a", "b", "", "radix", "radix", "radix ", "c"]);
+        assert_eq!(
+            passphrase.unwrap_err(),
+            "The passphrases didn't match 3 times."
+        );
+        assert_eq!(unused, 1);
+    }
+
+    #[test]
+    fn cancelled_prompt_is_prompt_error() {
+        for error in [
+            InquireError::OperationCanceled,
+            InquireError::OperationInterrupted,
+            InquireError::NotTTY,
+        ] {
+            let message = error.to_string();
+            assert_eq!(prompt_error(error), (message, ExitCode::Prompt));
+        }
+    }
+
+    #[test]
+    fn validate_mnemonic_empty() {
+        assert_eq!(
+            validate_mnemonic("  ").unwrap(),
+            Validation::Invalid("You did not input anything, please type your mnemonic.".into())
+        );
+        assert_eq!(
+            validate_mnemonic("zoo").unwrap(),
+            Validation::Invalid("Please type a valid mnemonic".into())
+        );
+        assert_eq!(validate_mnemonic("__test_0").unwrap(), Validation::Valid);
+    }
+
+    #[test]
+    fn validate_mnemonic_actionable() {
+        let twelve = |last| format!("{} {last}", ["abandon"; 11].join(" "));
+        assert_eq!(
+            validate_mnemonic(&twelve("abuot")).unwrap(),
+            Validation::Invalid(
+                "Unknown word 'abuot' at position 12 of the mnemonic, it is not a BIP-39 word, did you mean: about, abuse, adult, aunt?"
+                    .into()
+            )
+        );
+        assert_eq!(
+            validate_mnemonic(&twelve("xxxxxxx")).unwrap(),
+            Validation::Invalid(
+                "Unknown word 'xxxxxxx' at position 12 of the mnemonic, it is not a BIP-39 word."
+                    .into()
+            )
+        );
+        assert_eq!(
+            validate_mnemonic(&twelve("abandon")).unwrap(),
+            Validation::Invalid(format!("{}.", Error::InvalidMnemonicChecksum).into())
+        );
+        assert_eq!(
+            validate_mnemonic(&twelve("about")).unwrap(),
+            Validation::Valid
+        );
+    }
+}
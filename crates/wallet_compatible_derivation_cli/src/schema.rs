@@ -0,0 +1,30 @@
+/// The version of the account record object output with `--format json`,
+/// embedded as `schema_version` in every object. Bumped whenever a field is
+/// added, removed, renamed or changes type.
+pub(crate) const SCHEMA_VERSION: u32 = 1;
+
+/// The JSON Schema describing one account record object output with
+/// `--format json`, for downstream parsers to validate against.
+pub(crate) fn account_record_schema() -> String {
+    format!(
+        r#"{{
+  "$schema": "https://json-schema.org/draft/2020-12/schema",
+  "title": "Account record",
+  "description": "An account derived by wallet_compatible_derivation_cli, as output with `--format json`.",
+  "type": "object",
+  "properties": {{
+    "schema_version": {{ "description": "The version of this schema.", "const": {SCHEMA_VERSION} }},
+    "index": {{ "description": "The index of the account.", "type": "integer", "minimum": 0 }},
+    "address": {{ "description": "The bech32 encoded address of the account.", "type": "string" }},
+    "network": {{ "description": "The name of the network, e.g. `Mainnet`.", "type": "string" }},
+    "public_key": {{ "description": "The Ed25519 public key, hex encoded.", "type": "string", "pattern": "^[0-9a-f]{{64}}$" }},
+    "hd_path": {{ "description": "The BIP-32 derivation path, e.g. `m/44H/1022H/1H/525H/1460H/0H`.", "type": "string" }},
+    "factor_source_id": {{ "description": "The FactorSourceID of the mnemonic and passphrase, hex encoded.", "type": "string", "pattern": "^[0-9a-f]{{64}}$" }},
+    "private_key": {{ "description": "The Ed25519 private key, hex encoded, only output with `--include-private-key`.", "type": "string", "pattern": "^[0-9a-f]{{64}}$" }}
+  }},
+  "required": ["schema_version", "index", "address", "network", "public_key", "hd_path", "factor_source_id"],
+  "additionalProperties": false
+}}
+"#
+    )
+}
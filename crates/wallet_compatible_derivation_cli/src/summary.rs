@@ -0,0 +1,29 @@
+use std::time::Duration;
+use wallet_compatible_derivation::prelude::*;
+
+/// A one line summary of a run, e.g. `"Derived 1000 accounts on Mainnet in 3.2s"`,
+/// printed to stderr after deriving so that it does not pollute piped output.
+pub(crate) fn elapsed_summary(count: usize, network: &NetworkID, elapsed: Duration) -> String {
+    let noun = if count == 1 { "account" } else { "accounts" };
+    format!(
+        "Derived {count} {noun} on {network} in {:.1}s",
+        elapsed.as_secs_f64()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary() {
+        assert_eq!(
+            elapsed_summary(1000, &NetworkID::Mainnet, Duration::from_millis(3210)),
+            "Derived 1000 accounts on Mainnet in 3.2s"
+        );
+        assert_eq!(
+            elapsed_summary(1, &NetworkID::Stokenet, Duration::from_millis(40)),
+            "Derived 1 account on Stokenet in 0.0s"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use std::str::FromStr;
+use wallet_compatible_derivation::prelude::*;
+
+/// The account at `index` on `network_id` of the `__test_0` mnemonic and the
+/// empty passphrase, shared by the tests of the account formats.
+pub(crate) fn test_account(network_id: &NetworkID, index: HDPathComponentValue) -> Account {
+    Account::derive_unwrap(
+        &Mnemonic24Words::from_str("__test_0").unwrap(),
+        "",
+        &AccountPath::new(network_id, index),
+    )
+}
@@ -0,0 +1,105 @@
+use age::secrecy::SecretString;
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    path::Path,
+};
+
+/// Writes `contents` to a (new or truncated) file at `path`.
+///
+/// On Unix the file is only readable and writable by the current user (mode
+/// `0600`), since `contents` may contain private keys.
+///
+/// If an `encryption_passphrase` is given the contents are encrypted using
+/// the passphrase mode of the [age][age] file format, meaning the file can
+/// be decrypted using standard `age` tooling, e.g. `age -d <PATH>`. This is
+/// strongly recommended if `contents` contains private keys.
+///
+/// [age]: https://age-encryption.org/v1
+pub(crate) fn write_output(
+    path: &Path,
+    contents: &str,
+    encryption_passphrase: Option<String>,
+) -> std::io::Result<()> {
+    let file = create_private_file(path)?;
+    match encryption_passphrase {
+        Some(passphrase) => {
+            let encryptor = age::Encryptor::with_user_passphrase(SecretString::from(passphrase));
+            let mut writer = encryptor.wrap_output(file)?;
+            writer.write_all(contents.as_bytes())?;
+            writer.finish()?.flush()
+        }
+        None => {
+            let mut file = file;
+            file.write_all(contents.as_bytes())?;
+            file.flush()
+        }
+    }
+}
+
+/// Creates (or truncates) the file at `path`, with mode `0600` on Unix, also if
+/// the file already existed with a more permissive mode.
+fn create_private_file(path: &Path) -> std::io::Result<File> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        let file = options.open(path)?;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        Ok(file)
+    }
+    #[cfg(not(unix))]
+    options.open(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{io::Read, iter, path::PathBuf};
+
+    /// A path in the temporary directory, unique to this test process.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "wallet_compatible_derivation_cli_{name}_{}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn encrypted_output_roundtrip() {
+        let path = temp_path("encrypted_output");
+        write_output(&path, "secret", Some("radix".to_owned())).unwrap();
+
+        let ciphertext = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(!ciphertext.windows(6).any(|w| w == b"secret"));
+
+        let decryptor = age::Decryptor::new(ciphertext.as_slice()).unwrap();
+        let identity = age::scrypt::Identity::new(SecretString::from("radix".to_owned()));
+        let mut reader = decryptor
+            .decrypt(iter::once(&identity as &dyn age::Identity))
+            .unwrap();
+        let mut plaintext = String::new();
+        reader.read_to_string(&mut plaintext).unwrap();
+        assert_eq!(plaintext, "secret");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn existing_file_is_made_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let path = temp_path("private_output");
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_output(&path, "new", None).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(contents, "new");
+    }
+}
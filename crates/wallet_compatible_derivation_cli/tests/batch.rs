@@ -0,0 +1,46 @@
+mod common;
+
+use common::{cli, temp_path};
+use std::fs;
+
+#[test]
+fn input_csv_derives_every_row() {
+    let path = temp_path("batch.csv");
+    fs::write(
+        &path,
+        "mnemonic,passphrase,network,start,count
+__test_0,,stokenet,0,2
+__test_0,,mainnet,999,1
+",
+    )
+    .unwrap();
+    let output = cli().arg("--input-csv").arg(&path).output().unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let (first, second) = stdout.split_once("# Row 3").unwrap();
+    assert!(first.contains("# Row 2"));
+    assert!(first.contains("Network: Stokenet"));
+    assert!(first.contains("account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"));
+    assert!(first.contains("account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"));
+    assert!(second.contains("Network: Mainnet"));
+    assert!(second.contains("account_rdx12yhcv68333nlyrjh8xlek7cnqmj7e5zs7qr5fymeffkydrv2ru3avh"));
+    assert!(!stdout.contains("bright club"));
+}
+
+#[test]
+fn input_csv_reports_malformed_rows() {
+    let path = temp_path("batch_malformed.csv");
+    fs::write(&path, "zoo,,stokenet,0,1\n__test_0,,stokenet,0,1\n").unwrap();
+    let output = cli().arg("--input-csv").arg(&path).output().unwrap();
+    fs::remove_file(&path).unwrap();
+    assert!(!output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        "Row 1: Invalid mnemonic.\n"
+    );
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"));
+}
@@ -0,0 +1,17 @@
+//! Helpers shared by the integration tests, included with `mod common;`.
+#![allow(dead_code)]
+
+use std::{path::PathBuf, process::Command};
+
+/// A `Command` running the binary of the CLI.
+pub fn cli() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_wallet_compatible_derivation_cli"))
+}
+
+/// A path in the temporary directory, unique to this test process.
+pub fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "wallet_compatible_derivation_cli_{name}_{}",
+        std::process::id()
+    ))
+}
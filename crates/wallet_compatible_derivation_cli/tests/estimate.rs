@@ -0,0 +1,27 @@
+mod common;
+
+use common::cli;
+
+#[test]
+fn estimate_prints_eta_and_no_accounts() {
+    let output = cli()
+        .args(["--estimate", "--include-private-key"])
+        .args([
+            "no-pager",
+            "--mnemonic",
+            "__test_0",
+            "--network",
+            "stokenet",
+            "--count",
+            "255",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.starts_with("Estimated time to derive 255 accounts on Stokenet: "));
+    assert!(stdout.contains("(derived a sample of 100 in "));
+    assert!(!stdout.contains("account_tdx"));
+    assert!(!stdout.contains("PrivateKey"));
+}
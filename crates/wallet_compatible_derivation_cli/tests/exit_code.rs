@@ -0,0 +1,37 @@
+mod common;
+
+use common::cli;
+use std::process::Output;
+
+fn no_pager(mnemonic: &str, network: &str) -> Output {
+    cli()
+        .args(["no-pager", "--mnemonic", mnemonic, "--network", network])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn invalid_mnemonic_exits_with_3() {
+    let output = no_pager("zoo zoo zoo", "mainnet");
+    assert_eq!(output.status.code(), Some(3));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("--mnemonic"));
+}
+
+#[test]
+fn invalid_network_exits_with_4() {
+    let output = no_pager("__test_0", "enkinet");
+    assert_eq!(output.status.code(), Some(4));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("enkinet"));
+}
+
+#[test]
+fn usage_error_exits_with_2() {
+    let output = cli().args(["--no-such-flag"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
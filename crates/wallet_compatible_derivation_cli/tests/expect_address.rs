@@ -0,0 +1,46 @@
+mod common;
+
+use common::cli;
+use std::process::Output;
+
+fn derive_expecting(address: &str) -> Output {
+    cli()
+        .args(["--include-private-key", "--expect-address", address])
+        .args([
+            "no-pager",
+            "--mnemonic",
+            "__test_0",
+            "--network",
+            "stokenet",
+            "--start",
+            "1",
+        ])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn matching_address_succeeds() {
+    let output =
+        derive_expecting("account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("Address matches, derived at index 1 (m/44H/1022H/2H/525H/1460H/1H)"));
+    assert!(!stdout.contains("PrivateKey"));
+}
+
+#[test]
+fn mismatching_address_fails() {
+    let output =
+        derive_expecting("account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8");
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Address mismatch at index 1"));
+    assert!(stderr.contains(
+        "expected: account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"
+    ));
+    assert!(stderr.contains(
+        "derived:  account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+    ));
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::cli;
+
+fn factor_source_id(args: &[&str]) -> String {
+    let output = cli().arg("factor-source-id").args(args).output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn factor_source_id_of_test_0() {
+    assert_eq!(
+        factor_source_id(&["--mnemonic", "__test_0"]),
+        "6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033\n"
+    );
+}
+
+#[test]
+fn factor_source_id_depends_on_passphrase() {
+    assert_ne!(
+        factor_source_id(&["--mnemonic", "__test_0", "--passphrase", "radix"]),
+        factor_source_id(&["--mnemonic", "__test_0"])
+    );
+}
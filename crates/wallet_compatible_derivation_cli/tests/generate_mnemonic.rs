@@ -0,0 +1,22 @@
+mod common;
+
+use common::cli;
+
+fn generate_mnemonic() -> String {
+    let output = cli().arg("generate-mnemonic").output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn generates_random_24_words_mnemonic() {
+    let phrase = generate_mnemonic();
+    assert_eq!(phrase.split_whitespace().count(), 24);
+    assert_ne!(phrase, generate_mnemonic());
+
+    let status = cli()
+        .args(["factor-source-id", "--mnemonic", phrase.trim()])
+        .status()
+        .unwrap();
+    assert!(status.success());
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::{cli, temp_path};
+use std::process::Output;
+
+const PHRASE: &str = "bright club bacon dinner achieve pull grid save ramp cereal blush woman humble limb repeat video sudden possible story mask neutral prize goose mandate";
+const ADDRESS: &str = "account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8";
+
+fn derive(mnemonic_args: &[&str], envs: &[(&str, &str)]) -> Output {
+    cli()
+        .arg("no-pager")
+        .args(mnemonic_args)
+        .args(["--network", "stokenet", "--count", "1"])
+        .envs(envs.iter().copied())
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn mnemonic_from_file() {
+    let path = temp_path("mnemonic.txt");
+    std::fs::write(&path, format!("{PHRASE}\n")).unwrap();
+    let output = derive(&["--mnemonic-file", path.to_str().unwrap()], &[]);
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(ADDRESS));
+    assert!(!stdout.contains(PHRASE));
+}
+
+#[test]
+fn mnemonic_from_env() {
+    let var = "WALLET_COMPATIBLE_DERIVATION_TEST_MNEMONIC";
+    let output = derive(&["--mnemonic-env", var], &[(var, PHRASE)]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains(ADDRESS));
+}
+
+#[test]
+fn missing_file_is_io_error() {
+    let path = temp_path("does_not_exist.txt");
+    let output = derive(&["--mnemonic-file", path.to_str().unwrap()], &[]);
+    assert_eq!(output.status.code(), Some(7));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Failed to read mnemonic file"));
+}
+
+#[test]
+fn invalid_mnemonic_in_env() {
+    let var = "WALLET_COMPATIBLE_DERIVATION_TEST_INVALID_MNEMONIC";
+    let output = derive(&["--mnemonic-env", var], &[(var, "zoo zoo zoo")]);
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn conflicts_with_mnemonic() {
+    let output = derive(
+        &["--mnemonic", "__test_0", "--mnemonic-env", "SOME_VAR"],
+        &[],
+    );
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("cannot be used with"));
+}
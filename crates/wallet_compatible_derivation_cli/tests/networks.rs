@@ -0,0 +1,29 @@
+mod common;
+
+use common::cli;
+use wallet_compatible_derivation::prelude::*;
+
+#[test]
+fn lists_all_networks() {
+    let output = cli().arg("networks").output().unwrap();
+    assert!(output.status.success());
+    let table = String::from_utf8(output.stdout).unwrap();
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "NAME      ID  LOGICAL NAME  HRP SUFFIX");
+    assert_eq!(lines[1], "Mainnet   1   mainnet       rdx");
+    assert_eq!(lines.len(), NetworkID::all().len() + 1);
+    for (line, network_id) in lines[1..].iter().zip(NetworkID::all()) {
+        let cells = line.split_whitespace().collect::<Vec<_>>();
+        assert_eq!(
+            cells,
+            [
+                network_id.to_string(),
+                network_id.discriminant().to_string(),
+                network_id.logical_name(),
+                network_id.hrp_suffix(),
+            ]
+        );
+        assert_eq!(cells[0].parse::<NetworkID>(), Ok(network_id.clone()));
+        assert_eq!(cells[1].parse::<NetworkID>(), Ok(network_id));
+    }
+}
@@ -0,0 +1,44 @@
+mod common;
+
+use common::{cli, temp_path};
+use std::fs;
+
+#[test]
+fn output_is_written_to_private_file() {
+    let path = temp_path("output");
+    let status = cli()
+        .args(["--format", "csv", "--output"])
+        .arg(&path)
+        .args([
+            "no-pager",
+            "--mnemonic",
+            "__test_0",
+            "--network",
+            "stokenet",
+            "--start",
+            "0",
+            "--count",
+            "2",
+        ])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let contents = fs::read_to_string(&path).unwrap();
+    #[cfg(unix)]
+    let mode = {
+        use std::os::unix::fs::PermissionsExt;
+        fs::metadata(&path).unwrap().permissions().mode()
+    };
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        contents,
+        "index,address,public_key,path,factor_source_id
+0,account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8,5fdfa89b784cc63fc90f67bd3481f6611a798a9581b414bf627f758075e95ca1,m/44H/1022H/2H/525H/1460H/0H,6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033
+1,account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn,0c6cf91e9b669bf09aeff687c86f6158f8fdfb23d0034bd3cb3f95c4443e9324,m/44H/1022H/2H/525H/1460H/1H,6facb00a836864511fdf8f181382209e64e83ad462288ea1bc7868f236fb8033
+"
+    );
+    #[cfg(unix)]
+    assert_eq!(mode & 0o777, 0o600);
+}
@@ -0,0 +1,50 @@
+mod common;
+
+use common::cli;
+use std::process::Output;
+
+fn derive(args: &[&str], envs: &[(&str, &str)]) -> Output {
+    cli()
+        .args(["--quiet", "--include-private-key"])
+        .args(args)
+        .args([
+            "no-pager",
+            "--mnemonic",
+            "__test_0",
+            "--network",
+            "stokenet",
+            "--count",
+            "2",
+        ])
+        .envs(envs.iter().copied())
+        .output()
+        .unwrap()
+}
+
+fn assert_plain(output: Output) {
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.is_ascii(), "{stdout}");
+    assert!(stdout.contains(
+        "Address:          account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8"
+    ));
+    assert!(stdout.contains(
+        "Address:          account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+    ));
+}
+
+#[test]
+fn plain_contains_no_emoji() {
+    assert_plain(derive(&["--plain"], &[]));
+    assert_plain(derive(&["--no-color"], &[]));
+}
+
+#[test]
+fn no_color_env() {
+    assert_plain(derive(&[], &[("NO_COLOR", "1")]));
+}
+
+#[test]
+fn plain_if_stdout_is_not_terminal() {
+    assert_plain(derive(&[], &[]));
+}
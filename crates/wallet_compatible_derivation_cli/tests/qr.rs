@@ -0,0 +1,43 @@
+mod common;
+
+use common::cli;
+use std::process::Output;
+
+fn derive_with_qr(format: &str) -> Output {
+    cli()
+        .args(["--qr", "--quiet", "--format", format])
+        .args([
+            "no-pager",
+            "--mnemonic",
+            "__test_0",
+            "--network",
+            "stokenet",
+            "--count",
+            "1",
+        ])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn qr_follows_account_text() {
+    let output = derive_with_qr("text");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // Stdout is not a terminal, thus the account is printed plain, and the QR
+    // code follows it.
+    let (account, qr) = stdout.split_at(stdout.find('█').unwrap());
+    assert!(
+        account.contains("account_tdx_2_12x4rz8yh6t2qtpwdmzc2fvz9xvr00rvv37v7lk3eyh8re7z6r0xyw8")
+    );
+    assert!(qr.contains('█'));
+}
+
+#[test]
+fn qr_requires_text_format() {
+    let output = derive_with_qr("json");
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("`--qr` can only be used with `--format text`"));
+}
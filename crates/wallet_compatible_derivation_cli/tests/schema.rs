@@ -0,0 +1,175 @@
+mod common;
+
+use common::cli;
+use serde_json::{Map, Value};
+
+fn stdout(args: &[&str]) -> String {
+    let output = cli().args(args).output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn run(args: &[&str]) -> Value {
+    serde_json::from_str(&stdout(args)).unwrap()
+}
+
+fn derive_args<'a>(include_private_key: bool, extra: &[&'a str]) -> Vec<&'a str> {
+    let mut args = vec!["--format", "json"];
+    args.extend(extra);
+    if include_private_key {
+        args.push("--include-private-key");
+    }
+    args.extend([
+        "no-pager",
+        "--mnemonic",
+        "__test_0",
+        "--network",
+        "stokenet",
+        "--count",
+        "2",
+    ]);
+    args
+}
+
+/// Derives two accounts, output as `--json-style compact`, the default when
+/// stdout is not a terminal, one object per line.
+fn derive_json(include_private_key: bool) -> Vec<Value> {
+    stdout(&derive_args(include_private_key, &[]))
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+/// Validates `instance` against the subset of JSON Schema the account record
+/// schema uses, returning every violation.
+fn validate(schema: &Value, instance: &Value) -> Vec<String> {
+    let mut violations = Vec::new();
+    if let Some(expected) = schema.get("const") {
+        if expected != instance {
+            violations.push(format!("{instance} is not {expected}"));
+        }
+    }
+    let is_type = match schema.get("type").and_then(Value::as_str) {
+        Some("object") => instance.is_object(),
+        Some("string") => instance.is_string(),
+        Some("integer") => instance.is_u64() || instance.is_i64(),
+        Some(other) => panic!("Unsupported type '{other}'"),
+        None => true,
+    };
+    if !is_type {
+        violations.push(format!("{instance} is not of type {}", schema["type"]));
+    }
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_i64) {
+        if instance.as_i64().is_some_and(|i| i < minimum) {
+            violations.push(format!("{instance} is less than {minimum}"));
+        }
+    }
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        assert_eq!(pattern, "^[0-9a-f]{64}$");
+        let s = instance.as_str().unwrap_or_default();
+        if s.len() != 64 || !s.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')) {
+            violations.push(format!("{instance} does not match {pattern}"));
+        }
+    }
+    if let Some(object) = instance.as_object() {
+        violations.extend(validate_object(schema, object));
+    }
+    violations
+}
+
+fn validate_object(schema: &Value, object: &Map<String, Value>) -> Vec<String> {
+    let properties = schema["properties"].as_object().unwrap();
+    let mut violations = schema["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r.as_str().unwrap())
+        .filter(|r| !object.contains_key(*r))
+        .map(|r| format!("missing required '{r}'"))
+        .collect::<Vec<_>>();
+    for (key, value) in object {
+        match properties.get(key) {
+            Some(property) => violations.extend(validate(property, value)),
+            None if schema["additionalProperties"] == Value::Bool(false) => {
+                violations.push(format!("additional property '{key}'"))
+            }
+            None => {}
+        }
+    }
+    violations
+}
+
+#[test]
+fn json_output_validates_against_schema() {
+    let schema = run(&["--schema"]);
+    for include_private_key in [false, true] {
+        for account in derive_json(include_private_key) {
+            assert_eq!(validate(&schema, &account), Vec::<String>::new());
+            assert_eq!(account["schema_version"], 1);
+            assert_eq!(account.get("private_key").is_some(), include_private_key);
+        }
+    }
+}
+
+#[test]
+fn pretty_json_is_array_of_same_records() {
+    let pretty = run(&derive_args(true, &["--json-style", "pretty"]));
+    assert_eq!(pretty.as_array().unwrap(), &derive_json(true));
+    let compact = stdout(&derive_args(true, &["--json-style", "compact"]));
+    assert_eq!(compact.lines().count(), 2);
+}
+
+#[test]
+fn json_shorthands_eq_format_json_with_style() {
+    let shorthand = |flag| {
+        let mut args = vec![flag];
+        args.extend(&derive_args(false, &[])[2..]);
+        stdout(&args)
+    };
+    assert_eq!(
+        shorthand("--json"),
+        stdout(&derive_args(false, &["--json-style", "compact"]))
+    );
+    assert_eq!(
+        shorthand("--json-pretty"),
+        stdout(&derive_args(false, &["--json-style", "pretty"]))
+    );
+}
+
+#[test]
+fn json_shorthands_conflict_with_format() {
+    for args in [
+        vec!["--json", "--format", "csv"],
+        vec!["--json-pretty", "--json-style", "compact"],
+        vec!["--json", "--json-pretty"],
+    ] {
+        let output = cli()
+            .args(args)
+            .args(["no-pager", "--mnemonic", "__test_0"])
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+    }
+}
+
+#[test]
+fn schema_rejects_invalid_records() {
+    let schema = run(&["--schema"]);
+    let mut account = derive_json(false)[1].clone();
+    assert_eq!(
+        account["address"],
+        "account_tdx_2_12xwkvs77drhw7lxnw2aewrs264yhhkln7zzpejye66q6gt5mc2kphn"
+    );
+    let object = account.as_object_mut().unwrap();
+    object.remove("public_key");
+    object.insert("index".to_owned(), Value::from("1"));
+    object.insert("path".to_owned(), Value::from("m/44H"));
+    assert_eq!(
+        validate(&schema, &account),
+        vec![
+            "missing required 'public_key'".to_owned(),
+            r#""1" is not of type "integer""#.to_owned(),
+            "additional property 'path'".to_owned(),
+        ]
+    );
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::cli;
+use std::process::Output;
+
+const ENTROPY: &str = "1c0584451f201d5a999dfeb164b0627e76ef03adaf9ed895135a44394f569924";
+
+fn show_entropy(extra: &[&str], network: &str) -> Output {
+    cli()
+        .arg("--show-entropy")
+        .args(extra)
+        .args(["no-pager", "--mnemonic", "__test_0", "--network", network])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn mainnet_entropy_requires_acknowledgment() {
+    let output = show_entropy(&[], "mainnet");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--i-understand-mainnet-keys"));
+
+    let output = show_entropy(&["--i-understand-mainnet-keys"], "mainnet");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("Entropy: {ENTROPY}\n")
+    );
+}
+
+#[test]
+fn stokenet_entropy_needs_no_acknowledgment() {
+    let output = show_entropy(&[], "stokenet");
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        format!("Entropy: {ENTROPY}\n")
+    );
+}